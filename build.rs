@@ -0,0 +1,21 @@
+//! Renders the Markdown pages in `content/` to HTML at build time, so the
+//! app ships the HTML and no Markdown parser.
+
+use std::{env, fs, path::Path};
+
+use pulldown_cmark::{Options, Parser, html};
+
+const PAGES: &[&str] = &["privacy"];
+
+fn main() {
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    for page in PAGES {
+        let source = format!("content/{page}.md");
+        println!("cargo:rerun-if-changed={source}");
+
+        let markdown = fs::read_to_string(&source).unwrap_or_else(|e| panic!("reading {source}: {e}"));
+        let mut rendered = String::new();
+        html::push_html(&mut rendered, Parser::new_ext(&markdown, Options::ENABLE_TABLES));
+        fs::write(Path::new(&out_dir).join(format!("{page}.html")), rendered).expect("writing rendered page");
+    }
+}
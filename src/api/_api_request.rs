@@ -1,15 +1,111 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
 use anyhow::{Context, Result};
-use gloo_net::http::Request;
+use futures::future::{FutureExt, LocalBoxFuture, Shared};
+use gloo_net::http::{Request, Response};
 use serde::{Serialize, de::DeserializeOwned};
+use web_sys::AbortController;
 
-pub(super) async fn api_request<T>(
+use crate::api::auth_api::{LoginResponse, refresh_access_token};
+
+/// Bridge between the API layer and whatever keeps the session tokens.
+pub trait TokenProvider {
+    fn access_token(&self) -> Option<String>;
+    fn refresh_token(&self) -> Option<String>;
+    fn on_refreshed(&self, response: LoginResponse);
+    fn on_refresh_failed(&self);
+}
+
+type PendingRefresh = Shared<LocalBoxFuture<'static, Option<String>>>;
+
+/// How long a cached GET response is served before it is fetched again, in ms.
+const QUERY_CACHE_TTL: f64 = 60_000.0;
+
+struct CachedResponse {
+    body: String,
+    stored_at: f64,
+}
+
+thread_local! {
+    // GET response bodies keyed by URL, dropped on any authenticated write or logout and
+    // ignored once older than `QUERY_CACHE_TTL`
+    static QUERY_CACHE: RefCell<HashMap<String, CachedResponse>> = RefCell::new(HashMap::new());
+    // Shared by every request started since the last cancellation
+    static ABORT_CONTROLLER: RefCell<Option<AbortController>> = const { RefCell::new(None) };
+    static TOKEN_PROVIDER: RefCell<Option<Rc<dyn TokenProvider>>> = const { RefCell::new(None) };
+    // Requests that hit a 401 while a refresh is running wait on this instead of starting their own
+    static PENDING_REFRESH: RefCell<Option<PendingRefresh>> = const { RefCell::new(None) };
+}
+
+pub fn set_token_provider(provider: impl TokenProvider + 'static) {
+    TOKEN_PROVIDER.with_borrow_mut(|current| *current = Some(Rc::new(provider)));
+}
+
+/// Aborts every request that is still waiting for a response.
+pub fn cancel_pending_requests() {
+    if let Some(controller) = ABORT_CONTROLLER.with_borrow_mut(Option::take) {
+        controller.abort();
+    }
+}
+
+pub fn clear_query_cache() {
+    QUERY_CACHE.with_borrow_mut(HashMap::clear);
+}
+
+fn abort_controller() -> Option<AbortController> {
+    ABORT_CONTROLLER.with_borrow_mut(|controller| {
+        if controller.is_none() {
+            *controller = AbortController::new().ok();
+        }
+        controller.clone()
+    })
+}
+
+fn token_provider() -> Option<Rc<dyn TokenProvider>> {
+    TOKEN_PROVIDER.with_borrow(Clone::clone)
+}
+
+/// Refreshes the access token, or joins the refresh that is already running.
+/// Resolves to the new access token, `None` when the session could not be renewed.
+async fn refresh_session() -> Option<String> {
+    let pending = PENDING_REFRESH.with_borrow_mut(|pending| {
+        pending
+            .get_or_insert_with(|| {
+                async {
+                    let provider = token_provider();
+                    let response = match provider.as_ref().and_then(|p| p.refresh_token()) {
+                        Some(refresh_token) => refresh_access_token(refresh_token).await.ok(),
+                        None => None,
+                    };
+                    PENDING_REFRESH.with_borrow_mut(Option::take);
+                    let provider = provider?;
+
+                    match response {
+                        Some(response) => {
+                            let token = response.token.clone();
+                            provider.on_refreshed(response);
+                            Some(token)
+                        }
+                        None => {
+                            provider.on_refresh_failed();
+                            None
+                        }
+                    }
+                }
+                .boxed_local()
+                .shared()
+            })
+            .clone()
+    });
+    pending.await
+}
+
+async fn send_request(
     method: &str,
     url: &str,
-    body: Option<impl Serialize>,
-) -> Result<T>
-where
-    T: DeserializeOwned + 'static,
-{
+    body: Option<&str>,
+    token: Option<&str>,
+) -> Result<Response> {
     // 1. Initialize the RequestBuilder
     let mut builder = match method {
         "GET" => Request::get(url),
@@ -22,25 +118,108 @@ where
 
     // 2. Add common headers (Returns RequestBuilder)
     builder = builder.header("Content-Type", "application/json");
+    if let Some(token) = token {
+        builder = builder.header("Authorization", &format!("Bearer {token}"));
+    }
+    let controller = abort_controller();
+    builder = builder.abort_signal(controller.as_ref().map(|c| c.signal()).as_ref());
 
     // 3. Finalize into a Request object
-    // check if body is required to add -
+    let req = if let Some(body) = body {
+        // .body() consumes builder and returns Result<Request, Error>
+        builder.body(body.to_string())?
+    } else {
+        // .build() consumes builder and returns Result<Request, Error>
+        builder.build()?
+    };
+
+    // 4. Send finalized request
+    req.send().await.context("network request failed")
+}
+
+/// Sends a request carrying the session's access token. A 401 triggers a single
+/// token refresh after which the request is replayed once.
+pub(super) async fn api_request<T>(
+    method: &str,
+    url: &str,
+    body: Option<impl Serialize>,
+) -> Result<T>
+where
+    T: DeserializeOwned + 'static,
+{
+    request(method, url, body, true).await
+}
+
+/// Sends a request without an access token and without the refresh handling,
+/// for the auth endpoints themselves.
+pub(super) async fn public_request<T>(
+    method: &str,
+    url: &str,
+    body: Option<impl Serialize>,
+) -> Result<T>
+where
+    T: DeserializeOwned + 'static,
+{
+    request(method, url, body, false).await
+}
+
+async fn request<T>(
+    method: &str,
+    url: &str,
+    body: Option<impl Serialize>,
+    authorize: bool,
+) -> Result<T>
+where
+    T: DeserializeOwned + 'static,
+{
+    // 0. Serve fresh GETs from the cache, authenticated writes invalidate it
+    if method == "GET" {
+        let now = js_sys::Date::now();
+        let cached = QUERY_CACHE.with_borrow_mut(|cache| {
+            cache.retain(|_, cached| now - cached.stored_at < QUERY_CACHE_TTL);
+            cache.get(url).map(|cached| cached.body.clone())
+        });
+        if let Some(cached) = cached {
+            return serde_json::from_str(&cached).context("failed to parse JSON response");
+        }
+    } else if authorize {
+        // Public writes (login, contact form) don't touch the cached API data
+        clear_query_cache();
+    }
+
+    // check if body is required to add, it is serialized once so it can be replayed -
     let add_req_body = match method {
         "POST" => true,
         "PUT" => true,
         "PATCH" => true,
         _ => false,
     };
-    let req = if add_req_body == true && let Some(body) = body {
-        // .json() consumes builder and returns Result<Request, Error>
-        builder.json(&body)?
+    let body = match body {
+        Some(body) if add_req_body => Some(serde_json::to_string(&body)?),
+        _ => None,
+    };
+
+    let token = if authorize {
+        token_provider().and_then(|provider| provider.access_token())
     } else {
-        // .build() consumes builder and returns Result<Request, Error>
-        builder.build()?
+        None
     };
+    let mut resp = send_request(method, url, body.as_deref(), token.as_deref()).await?;
 
-    // 4. Send finalized request
-    let resp = req.send().await.context("network request failed")?;
+    if resp.status() == 401 && token.is_some() {
+        // Another request may have refreshed the token while this one was in flight
+        let current = token_provider().and_then(|provider| provider.access_token());
+        let fresh_token = match current {
+            Some(current) if Some(&current) != token.as_ref() => Some(current),
+            _ => refresh_session().await,
+        };
+        match fresh_token {
+            Some(fresh_token) => {
+                resp = send_request(method, url, body.as_deref(), Some(&fresh_token)).await?;
+            }
+            None => anyhow::bail!("HTTP 401 session expired"),
+        }
+    }
 
     let status = resp.status();
     if status >= 400 {
@@ -48,7 +227,11 @@ where
         anyhow::bail!("HTTP {status} {status_text}");
     }
 
-    resp.json::<T>()
-        .await
-        .context("failed to parse JSON response")
+    let text = resp.text().await.context("failed to read response body")?;
+    let parsed = serde_json::from_str(&text).context("failed to parse JSON response")?;
+    if method == "GET" {
+        let cached = CachedResponse { body: text, stored_at: js_sys::Date::now() };
+        QUERY_CACHE.with_borrow_mut(|cache| cache.insert(url.to_string(), cached));
+    }
+    Ok(parsed)
 }
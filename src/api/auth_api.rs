@@ -1,7 +1,6 @@
-use crate::api::_api_request::api_request;
+use crate::api::_api_request::public_request;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use leptos::logging::log;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct LoginRequest {
@@ -12,6 +11,17 @@ pub struct LoginRequest {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct LoginResponse {
     pub token: String,
+    /// Only issued by backends with short-lived access tokens
+    #[serde(default, alias = "refreshToken", skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+    /// Access token lifetime in seconds
+    #[serde(default, alias = "expiresIn", skip_serializing_if = "Option::is_none")]
+    pub expires_in: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RefreshRequest {
+    pub refresh_token: String,
 }
 
 fn base_url() -> &'static str {
@@ -20,8 +30,10 @@ fn base_url() -> &'static str {
 
 pub async fn try_login(credentials: LoginRequest) -> Result<LoginResponse> {
     let url = format!("{}auth/login", base_url());
-    let response: LoginResponse = api_request("POST", &url, Some(credentials)).await?;
+    public_request("POST", &url, Some(credentials)).await
+}
 
-    log!("try_login, response: {:#?}", response);
-    Ok(response)
+pub async fn refresh_access_token(refresh_token: String) -> Result<LoginResponse> {
+    let url = format!("{}auth/refresh", base_url());
+    public_request("POST", &url, Some(RefreshRequest { refresh_token })).await
 }
@@ -5,14 +5,26 @@ use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::option::Option::None;
 
-// imported from ./product_api file
-use crate::api::product_api::Product;
+/// FakeStore requires a `userId` on every cart. Orders placed without an
+/// account are posted with this placeholder, which matches no user.
+pub const GUEST_USER_ID: u32 = 0;
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
 pub struct Cart {
-    id: u32,
-    user_id: u32,
-    products: Vec<Product>,
+    pub id: u32,
+    pub user_id: u32,
+    /// ISO 8601 timestamp
+    #[serde(default)]
+    pub date: String,
+    pub products: Vec<CartItem>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CartItem {
+    pub product_id: u32,
+    pub quantity: u32,
 }
 
 impl fmt::Display for Cart {
@@ -45,6 +57,14 @@ pub async fn get_a_cart(cart_id: u32) -> Result<Cart> {
     Ok(cart)
 }
 
+pub async fn get_user_carts(user_id: u32) -> Result<Vec<Cart>> {
+    let url = format!("{}carts/user/{}", base_url(), user_id);
+    let carts: Vec<Cart> = api_request("GET", &url, None::<()>).await?;
+
+    log!("[get_user_carts], response: {:#?}", carts);
+    Ok(carts)
+}
+
 pub async fn add_a_cart(new_cart: Cart) -> Result<Cart> {
     let url = format!("{}carts", base_url());
     let cart: Cart = api_request("POST", &url, Some(new_cart)).await?;
@@ -54,7 +74,7 @@ pub async fn add_a_cart(new_cart: Cart) -> Result<Cart> {
 }
 
 pub async fn update_a_cart(updated_cart: Cart) -> Result<Cart> {
-    let url = format!("{}carts", base_url());
+    let url = format!("{}carts/{}", base_url(), updated_cart.id);
     let cart: Cart = api_request("PUT", &url, Some(updated_cart)).await?;
 
     log!("[update_a_cart], response: {:#?}", cart);
@@ -0,0 +1,23 @@
+use crate::api::_api_request::public_request;
+use anyhow::Result;
+use serde::{Serialize, de::IgnoredAny};
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct ContactMessage {
+    pub name: String,
+    pub email: String,
+    pub subject: String,
+    pub message: String,
+}
+
+/// Where the contact form posts. Defaults to JSONPlaceholder, which accepts
+/// any JSON and replies 201, so the form works without a real backend.
+fn contact_url() -> &'static str {
+    option_env!("CONTACT_URL").unwrap_or("https://jsonplaceholder.typicode.com/posts")
+}
+
+/// POST the message; any 2xx JSON reply counts as delivered.
+pub async fn send_contact_message(message: ContactMessage) -> Result<()> {
+    let _: IgnoredAny = public_request("POST", contact_url(), Some(message)).await?;
+    Ok(())
+}
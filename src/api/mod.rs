@@ -1,5 +1,8 @@
 pub mod auth_api;
 pub mod cart_api;
+pub mod contact_api;
 pub mod product_api;
 pub mod users_api;
-mod _api_request;
\ No newline at end of file
+mod _api_request;
+
+pub use _api_request::{TokenProvider, cancel_pending_requests, clear_query_cache, set_token_provider};
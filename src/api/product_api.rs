@@ -5,14 +5,22 @@ use crate::api::_api_request::api_request;
 use std::option::Option::None;
 use leptos::logging::log;
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
 pub struct Product {
-    id: u32,
-    title: String,
-    price: f64,
-    description: String,
-    category: String,
-    image: String,
+    pub id: u32,
+    pub title: String,
+    pub price: f64,
+    pub description: String,
+    pub category: String,
+    pub image: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rating: Option<Rating>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct Rating {
+    pub rate: f64,
+    pub count: u32,
 }
 
 impl fmt::Display for Product {
@@ -38,6 +46,14 @@ pub async fn get_all_products() -> Result<Vec<Product>> {
     Ok(products)
 }
 
+pub async fn get_all_categories() -> Result<Vec<String>> {
+    let url = format!("{}products/categories", base_url());
+    let categories: Vec<String> = api_request("GET", &url, None::<()>).await?;
+
+    log!("[get_all_categories], response: {:#?}", categories);
+    Ok(categories)
+}
+
 pub async fn get_a_product(product_id: u32) -> Result<Product> {
     let url = format!("{}products/{}", base_url(), product_id);
     let product: Product = api_request("GET", &url, None::<()>).await?;
@@ -55,7 +71,7 @@ pub async fn add_a_product(new_product: Product) -> Result<Product> {
 }
 
 pub async fn update_a_product(updated_product: Product) -> Result<Product> {
-    let url = format!("{}products", base_url());
+    let url = format!("{}products/{}", base_url(), updated_product.id);
     let product: Product = api_request("PUT", &url, Some(updated_product)).await?;
 
     log!("[update_a_product], response: {:#?}", product);
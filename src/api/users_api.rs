@@ -5,12 +5,33 @@ use crate::api::_api_request::api_request;
 use std::option::Option::None;
 use leptos::logging::log;
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
 pub struct User {
     pub id: u32,
     pub username: String,
     pub email: String,
     pub password: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Name>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Name {
+    pub firstname: String,
+    pub lastname: String,
+}
+
+/// Postal address as FakeStore stores it, the geolocation is not used.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Address {
+    pub city: String,
+    pub street: String,
+    pub number: u32,
+    pub zipcode: String,
 }
 
 impl fmt::Display for User {
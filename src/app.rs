@@ -1,33 +1,37 @@
 use leptos::prelude::*;
-use leptos_router::components::{ParentRoute, Route, Router, Routes};
-use leptos_router::path;
+use leptos_meta::provide_meta_context;
+use leptos_router::components::Router;
 
-use crate::layouts::{private_layout::PrivateLayout, public_layout::PublicLayout};
-use crate::pages::{dashboard::Dashboard, home::Home, login::Login, not_found::NotFound};
+use crate::cart::{
+    guest::provide_guest_cart,
+    payment::{FakePaymentProvider, provide_payment_provider},
+};
+use crate::components::ui::{
+    page_title::PageTitle,
+    route_announcer::RouteAnnouncer,
+    toast::{Toasts, provide_toaster},
+};
+use crate::routes::AppRoutes;
+use crate::session::{
+    context::{SessionConfig, provide_session},
+    logout::provide_logout,
+};
 
 #[component]
 pub fn app() -> impl IntoView {
+    provide_meta_context();
+    provide_session(SessionConfig::default());
+    provide_logout();
+    provide_toaster();
+    provide_guest_cart();
+    provide_payment_provider(FakePaymentProvider::default());
+
     view! {
         <Router>
-            <Routes fallback=NotFound>
-                // 1. Public Routes
-                <ParentRoute path=path!("") view=PublicLayout>
-                    <Route path=path!("/") view=Home />
-                    <Route path=path!("/login") view=Login />
-                </ParentRoute>
-
-                // 2. Private Routes
-                <ParentRoute path=path!("") view=PrivateLayout>
-                    <Route path=path!("/dashboard") view=Dashboard />
-                    <Route path=path!("/users") view=Dashboard />
-                    <Route path=path!("/users/create-new") view=Dashboard />
-                    <Route path=path!("/users/update") view=Dashboard />
-                    <Route path=path!("/products") view=Dashboard />
-                    <Route path=path!("/products/create-new") view=Dashboard />
-                    <Route path=path!("/products/update-product") view=Dashboard />
-                    <Route path=path!("/cart") view=Dashboard />
-                </ParentRoute>
-            </Routes>
+            <PageTitle />
+            <AppRoutes />
+            <RouteAnnouncer />
+            <Toasts />
         </Router>
     }
 }
@@ -0,0 +1,145 @@
+use crate::api::cart_api::{Cart, CartItem, add_a_cart, get_user_carts, update_a_cart};
+use gloo_storage::{LocalStorage, Storage};
+use leptos::{ev, prelude::*};
+use leptos_use::use_event_listener;
+
+const GUEST_CART_KEY: &str = "guest_cart";
+
+/// Cart of a visitor who is not signed in, shared through context and kept in
+/// localStorage so it survives reloads and follows edits made in other tabs.
+#[derive(Clone, Copy)]
+pub struct GuestCart {
+    items: RwSignal<Vec<CartItem>>,
+    drawer_open: RwSignal<bool>,
+}
+
+impl GuestCart {
+    pub fn items(&self) -> Vec<CartItem> {
+        self.items.get()
+    }
+
+    /// Total quantity across all lines.
+    pub fn item_count(&self) -> u32 {
+        self.items.with(|items| items.iter().map(|item| item.quantity).sum())
+    }
+
+    pub fn quantity_of(&self, product_id: u32) -> u32 {
+        self.items.with(|items| {
+            items
+                .iter()
+                .find(|item| item.product_id == product_id)
+                .map_or(0, |item| item.quantity)
+        })
+    }
+
+    pub fn add(&self, product_id: u32, quantity: u32) {
+        self.items.update(|items| merge_line(items, CartItem { product_id, quantity }));
+    }
+
+    /// A quantity of zero removes the line.
+    pub fn set_quantity(&self, product_id: u32, quantity: u32) {
+        if quantity == 0 {
+            self.remove(product_id);
+            return;
+        }
+        self.items.update(|items| {
+            if let Some(item) = items.iter_mut().find(|item| item.product_id == product_id) {
+                item.quantity = quantity;
+            }
+        });
+    }
+
+    pub fn remove(&self, product_id: u32) {
+        self.items.update(|items| items.retain(|item| item.product_id != product_id));
+    }
+
+    pub fn clear(&self) {
+        self.items.set(Vec::new());
+    }
+
+    pub fn is_drawer_open(&self) -> bool {
+        self.drawer_open.get()
+    }
+
+    pub fn open_drawer(&self) {
+        self.drawer_open.set(true);
+    }
+
+    pub fn close_drawer(&self) {
+        self.drawer_open.set(false);
+    }
+
+    /// Moves the guest lines into the account's latest server cart, or a new
+    /// one, and empties the guest cart. On failure the guest cart is kept.
+    pub async fn merge_into_account(&self, user_id: u32) -> anyhow::Result<Option<Cart>> {
+        let items = self.items.get_untracked();
+        if items.is_empty() {
+            return Ok(None);
+        }
+
+        let latest = get_user_carts(user_id)
+            .await?
+            .into_iter()
+            .max_by(|a, b| a.date.cmp(&b.date));
+        let saved = match latest {
+            Some(mut cart) => {
+                for item in items {
+                    merge_line(&mut cart.products, item);
+                }
+                update_a_cart(cart).await?
+            }
+            None => {
+                add_a_cart(Cart {
+                    id: 0,
+                    user_id,
+                    date: String::from(js_sys::Date::new_0().to_iso_string()),
+                    products: items,
+                })
+                .await?
+            }
+        };
+
+        self.clear();
+        Ok(Some(saved))
+    }
+}
+
+fn merge_line(items: &mut Vec<CartItem>, line: CartItem) {
+    match items.iter_mut().find(|item| item.product_id == line.product_id) {
+        Some(item) => item.quantity += line.quantity,
+        None => items.push(line),
+    }
+}
+
+pub fn provide_guest_cart() -> GuestCart {
+    let cart = GuestCart {
+        items: RwSignal::new(LocalStorage::get(GUEST_CART_KEY).unwrap_or_default()),
+        drawer_open: RwSignal::new(false),
+    };
+
+    Effect::new(move |_| {
+        let items = cart.items.get();
+        if items.is_empty() {
+            LocalStorage::delete(GUEST_CART_KEY);
+        } else {
+            let _ = LocalStorage::set(GUEST_CART_KEY, &items);
+        }
+    });
+
+    // `storage` only fires for writes made by other tabs
+    let _ = use_event_listener(window(), ev::storage, move |event| {
+        if event.key().as_deref() == Some(GUEST_CART_KEY) {
+            let items: Vec<CartItem> = LocalStorage::get(GUEST_CART_KEY).unwrap_or_default();
+            if items != cart.items.get_untracked() {
+                cart.items.set(items);
+            }
+        }
+    });
+
+    provide_context(cart);
+    cart
+}
+
+pub fn use_guest_cart() -> GuestCart {
+    expect_context::<GuestCart>()
+}
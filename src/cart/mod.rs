@@ -0,0 +1,4 @@
+// cart/mod.rs
+
+pub mod guest;
+pub mod payment;
\ No newline at end of file
@@ -0,0 +1,132 @@
+use futures::{channel::oneshot, future::LocalBoxFuture};
+use leptos::prelude::*;
+use std::{fmt, sync::Arc, time::Duration};
+
+/// Card that the fake provider always declines, for trying the error path.
+pub const DECLINED_TEST_CARD: &str = "4000000000000002";
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CardDetails {
+    pub holder: String,
+    pub number: String,
+    /// `MM/YY`
+    pub expiry: String,
+    pub cvc: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct PaymentRequest {
+    pub amount: f64,
+    pub currency: &'static str,
+    pub card: CardDetails,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PaymentReceipt {
+    /// Provider side id of the charge
+    pub reference: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum PaymentError {
+    Declined,
+    Failed(String),
+}
+
+impl fmt::Display for PaymentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PaymentError::Declined => write!(f, "The card was declined."),
+            PaymentError::Failed(reason) => write!(f, "Payment failed: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for PaymentError {}
+
+/// Charges a card. Checkout only talks to this trait, so a real gateway can
+/// replace `FakePaymentProvider` through `provide_payment_provider`.
+pub trait PaymentProvider: Send + Sync {
+    fn name(&self) -> &'static str;
+    fn charge(&self, request: PaymentRequest) -> LocalBoxFuture<'static, Result<PaymentReceipt, PaymentError>>;
+}
+
+/// Local stand-in that approves every card except `DECLINED_TEST_CARD`,
+/// after a short delay so the pending state is visible.
+pub struct FakePaymentProvider {
+    pub latency: Duration,
+}
+
+impl Default for FakePaymentProvider {
+    fn default() -> Self {
+        Self { latency: Duration::from_millis(800) }
+    }
+}
+
+impl PaymentProvider for FakePaymentProvider {
+    fn name(&self) -> &'static str {
+        "Test payments"
+    }
+
+    fn charge(&self, request: PaymentRequest) -> LocalBoxFuture<'static, Result<PaymentReceipt, PaymentError>> {
+        let latency = self.latency;
+        Box::pin(async move {
+            let (done, wait) = oneshot::channel();
+            set_timeout(
+                move || {
+                    let _ = done.send(());
+                },
+                latency,
+            );
+            wait.await.map_err(|_| PaymentError::Failed("cancelled".to_string()))?;
+
+            if request.card.number.replace(' ', "") == DECLINED_TEST_CARD {
+                return Err(PaymentError::Declined);
+            }
+            Ok(PaymentReceipt {
+                reference: format!("fake_{}", js_sys::Date::now() as u64),
+            })
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct PaymentGateway(Arc<dyn PaymentProvider>);
+
+impl PaymentGateway {
+    pub fn provider(&self) -> &dyn PaymentProvider {
+        self.0.as_ref()
+    }
+}
+
+pub fn provide_payment_provider(provider: impl PaymentProvider + 'static) {
+    provide_context(PaymentGateway(Arc::new(provider)));
+}
+
+pub fn use_payment_provider() -> PaymentGateway {
+    expect_context::<PaymentGateway>()
+}
+
+/// Luhn check over the digits of `number`, spaces are ignored.
+pub fn is_valid_card_number(number: &str) -> bool {
+    let digits: Vec<u32> = number
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_digit(10))
+        .collect::<Option<_>>()
+        .unwrap_or_default();
+    if !(12..=19).contains(&digits.len()) {
+        return false;
+    }
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, &digit)| match index % 2 {
+            0 => digit,
+            _ if digit * 2 > 9 => digit * 2 - 9,
+            _ => digit * 2,
+        })
+        .sum();
+    sum % 10 == 0
+}
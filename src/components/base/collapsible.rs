@@ -12,14 +12,17 @@ struct CollapsibleContext {
 #[component]
 pub fn Collapsible(
     #[prop(optional, default = false)] default_open: bool,
+    /// Controlled open state, for opening the collapsible from outside
+    #[prop(optional)] open: Option<RwSignal<bool>>,
     #[prop(optional, into)] class: String,
     #[prop(attrs)] attrs: Vec<AnyAttribute>,
     children: Children,
 ) -> impl IntoView {
-    let (is_open, set_is_open) = signal(default_open);
+    let state = open.unwrap_or_else(|| RwSignal::new(default_open));
+    let is_open = state.read_only();
 
     let toggle = Callback::new(move |_| {
-        set_is_open.update(|state| *state = !*state);
+        state.update(|state| *state = !*state);
     });
 
     provide_context(CollapsibleContext { is_open, toggle });
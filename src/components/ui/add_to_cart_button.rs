@@ -0,0 +1,33 @@
+use crate::cart::guest::use_guest_cart;
+use crate::components::base::button::{Button, ButtonSize};
+use crate::components::base::icons::*;
+use crate::components::ui::toast::use_toaster;
+use leptos::prelude::*;
+
+#[component]
+pub fn AddToCartButton(
+    product_id: u32,
+    /// Product title used in the confirmation toast
+    #[prop(into)] title: String,
+    #[prop(optional)] size: ButtonSize,
+    #[prop(optional, into)] class: String,
+) -> impl IntoView {
+    let cart = use_guest_cart();
+    let toaster = use_toaster();
+    let title = StoredValue::new(title);
+
+    view! {
+        <Button
+            size=size
+            class=class
+            attr:aria-label=move || title.with_value(|title| format!("Add {title} to cart"))
+            on_click=move || {
+                cart.add(product_id, 1);
+                toaster.success(title.with_value(|title| format!("Added {title} to your cart.")));
+            }
+        >
+            <IconView icon=Icon::CART_PLUS.clone() />
+            "Add to cart"
+        </Button>
+    }
+}
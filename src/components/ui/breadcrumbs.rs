@@ -0,0 +1,57 @@
+use crate::api::product_api::get_a_product;
+use crate::cn;
+use crate::components::base::icons::*;
+use crate::routes::{AppRoute, use_current_route};
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+/// Label of `route` in breadcrumbs and the document title. Detail pages use
+/// the name of the record they show once it has loaded.
+pub fn use_route_label(route: AppRoute) -> Signal<String> {
+    let resolved = LocalResource::new(move || async move {
+        match route {
+            AppRoute::ProductDetail { id } | AppRoute::ShopProduct { id } => {
+                get_a_product(id).await.ok().map(|product| product.title)
+            }
+            AppRoute::CartDetail { id } => Some(format!("Cart #{id}")),
+            _ => None,
+        }
+    });
+    Signal::derive(move || resolved.get().flatten().unwrap_or_else(|| route.title().to_string()))
+}
+
+/// Trail from the top level route down to the current one.
+#[component]
+pub fn Breadcrumbs(#[prop(optional, into)] class: String) -> impl IntoView {
+    let current = use_current_route();
+
+    view! {
+        <nav aria-label="Breadcrumb" class=cn!("min-w-0", class)>
+            <ol class="flex items-center gap-1.5 text-sm text-muted-foreground">
+                {move || current.get().map(|route| {
+                    let trail = route.trail();
+                    let last = trail.len() - 1;
+                    trail
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, crumb)| {
+                            let label = use_route_label(crumb);
+                            if index == last {
+                                view! {
+                                    <li class="truncate font-medium text-foreground" aria-current="page">{label}</li>
+                                }.into_any()
+                            } else {
+                                view! {
+                                    <li class="flex min-w-0 items-center gap-1.5">
+                                        <A href=crumb.href() attr:class="truncate hover:text-foreground">{label}</A>
+                                        <IconView icon=Icon::CHEVRON_RIGHT class="size-3.5 shrink-0" />
+                                    </li>
+                                }.into_any()
+                            }
+                        })
+                        .collect_view()
+                })}
+            </ol>
+        </nav>
+    }
+}
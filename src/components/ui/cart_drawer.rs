@@ -0,0 +1,150 @@
+use crate::api::product_api::get_all_products;
+use crate::cart::guest::use_guest_cart;
+use crate::components::base::button::{Button, ButtonSize, ButtonVariant};
+use crate::components::base::icons::*;
+use crate::routes::AppRoute;
+use crate::utils::currency::format_price;
+use leptos::prelude::*;
+use leptos_router::components::A;
+use std::collections::HashMap;
+
+/// Slide-over listing the guest cart, opened from the navbar cart button.
+#[component]
+pub fn CartDrawer() -> impl IntoView {
+    let cart = use_guest_cart();
+    // Served from the query cache once the storefront has loaded the catalogue
+    let products = LocalResource::new(|| async {
+        get_all_products()
+            .await
+            .map(|products| products.into_iter().map(|product| (product.id, product)).collect::<HashMap<_, _>>())
+            .unwrap_or_default()
+    });
+
+    let total = move || {
+        products.with(|products| {
+            let Some(products) = products else { return 0.0 };
+            cart.items()
+                .iter()
+                .filter_map(|item| products.get(&item.product_id).map(|product| product.price * item.quantity as f64))
+                .sum::<f64>()
+        })
+    };
+
+    view! {
+        <Show when=move || cart.is_drawer_open()>
+            <div
+                class="fixed inset-0 z-40 flex justify-end bg-black/30 animate-fade-in"
+                on:click=move |_| cart.close_drawer()
+                on:keydown=move |ev| if ev.key() == "Escape" { cart.close_drawer() }
+            >
+                <aside
+                    role="dialog"
+                    aria-modal="true"
+                    aria-labelledby="cart-drawer-title"
+                    class="flex h-full w-full max-w-md flex-col bg-background shadow-xl animate-slide-in-right"
+                    on:click=|ev| ev.stop_propagation()
+                >
+                    <div class="flex items-center justify-between border-b p-4">
+                        <h2 id="cart-drawer-title" class="text-lg font-semibold">
+                            {move || format!("Your cart ({})", cart.item_count())}
+                        </h2>
+                        <Button variant=ButtonVariant::Ghost size=ButtonSize::Sm attr:autofocus=true on_click=move || cart.close_drawer()>
+                            "Close"
+                        </Button>
+                    </div>
+
+                    <div class="flex-1 overflow-y-auto p-4">
+                        <Show
+                            when=move || cart.item_count() > 0
+                            fallback=|| view! {
+                                <div class="flex flex-col items-center gap-2 py-16 text-center text-muted-foreground">
+                                    <IconView icon=Icon::SHOPPING_CART.clone() class="size-8" />
+                                    <p>"Your cart is empty."</p>
+                                </div>
+                            }
+                        >
+                            <ul class="divide-y">
+                                <For
+                                    each=move || cart.items()
+                                    key=|item| item.product_id
+                                    children=move |item| {
+                                        let product_id = item.product_id;
+                                        let product = move || products.with(|products| {
+                                            products.as_ref().and_then(|products| products.get(&product_id).cloned())
+                                        });
+                                        let quantity = move || cart.quantity_of(product_id);
+                                        view! {
+                                            <li class="flex gap-3 py-4">
+                                                {move || match product() {
+                                                    Some(product) => view! {
+                                                        <img src=product.image.clone() alt="" class="size-16 shrink-0 rounded bg-white object-contain" />
+                                                        <div class="min-w-0 flex-1 space-y-1">
+                                                            <A href=AppRoute::ShopProduct { id: product_id }.href() attr:class="line-clamp-2 text-sm font-medium hover:underline">
+                                                                {product.title.clone()}
+                                                            </A>
+                                                            <p class="text-sm text-muted-foreground tabular-nums">
+                                                                {format_price(product.price)}
+                                                            </p>
+                                                        </div>
+                                                    }.into_any(),
+                                                    None => view! {
+                                                        <p class="flex-1 text-sm text-muted-foreground">{format!("Product #{product_id}")}</p>
+                                                    }.into_any(),
+                                                }}
+                                                <div class="flex flex-col items-end gap-2">
+                                                    <div class="flex items-center gap-1">
+                                                        <Button
+                                                            variant=ButtonVariant::Outline
+                                                            size=ButtonSize::Icon
+                                                            class="size-7".to_string()
+                                                            attr:aria-label="Decrease quantity"
+                                                            on_click=move || cart.set_quantity(product_id, quantity().saturating_sub(1))
+                                                        >
+                                                            "−"
+                                                        </Button>
+                                                        <span class="w-8 text-center text-sm tabular-nums" aria-live="polite">{quantity}</span>
+                                                        <Button
+                                                            variant=ButtonVariant::Outline
+                                                            size=ButtonSize::Icon
+                                                            class="size-7".to_string()
+                                                            attr:aria-label="Increase quantity"
+                                                            on_click=move || cart.set_quantity(product_id, quantity() + 1)
+                                                        >
+                                                            "+"
+                                                        </Button>
+                                                    </div>
+                                                    <button class="text-xs text-destructive hover:underline" on:click=move |_| cart.remove(product_id)>
+                                                        "Remove"
+                                                    </button>
+                                                </div>
+                                            </li>
+                                        }
+                                    }
+                                />
+                            </ul>
+                        </Show>
+                    </div>
+
+                    <div class="space-y-3 border-t p-4">
+                        <div class="flex items-center justify-between font-medium">
+                            <span>"Subtotal"</span>
+                            <span class="tabular-nums">{move || format_price(total())}</span>
+                        </div>
+                        <Show when=move || cart.item_count() > 0>
+                            <A
+                                href=format!("{}?step=review", AppRoute::Checkout.href())
+                                on:click=move |_| cart.close_drawer()
+                                attr:class="flex h-9 w-full items-center justify-center rounded-md bg-primary text-sm font-medium text-primary-foreground hover:bg-primary/90"
+                            >
+                                "Checkout"
+                            </A>
+                        </Show>
+                        <Button variant=ButtonVariant::Outline class="w-full".to_string() on_click=move || cart.close_drawer()>
+                            "Continue shopping"
+                        </Button>
+                    </div>
+                </aside>
+            </div>
+        </Show>
+    }
+}
@@ -0,0 +1,104 @@
+use crate::cn;
+use leptos::prelude::*;
+
+/// Series colours, one per `--chart-N` token. Spelled out so Tailwind picks them up.
+const CHART_BACKGROUNDS: [&str; 5] = ["bg-chart-1", "bg-chart-2", "bg-chart-3", "bg-chart-4", "bg-chart-5"];
+const CHART_FILLS: [&str; 5] = ["fill-chart-1", "fill-chart-2", "fill-chart-3", "fill-chart-4", "fill-chart-5"];
+
+const COLUMN_CHART_HEIGHT: f64 = 160.0;
+const COLUMN_WIDTH: f64 = 32.0;
+const COLUMN_GAP: f64 = 16.0;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChartPoint {
+    pub label: String,
+    pub value: f64,
+}
+
+fn max_value(points: &[ChartPoint]) -> f64 {
+    points.iter().map(|point| point.value).fold(0.0, f64::max)
+}
+
+/// Horizontal bars, each in the next chart colour.
+#[component]
+pub fn BarChart(
+    #[prop(into)] points: Signal<Vec<ChartPoint>>,
+    /// Renders the value shown next to each bar
+    #[prop(optional)] format: Option<fn(f64) -> String>,
+    #[prop(optional, into)] class: String,
+) -> impl IntoView {
+    let format = format.unwrap_or(|value| value.to_string());
+
+    view! {
+        <ul class=cn!("space-y-3", class)>
+            {move || {
+                let points = points.get();
+                let max = max_value(&points);
+                points.into_iter().enumerate().map(|(index, point)| {
+                    let width = if max > 0.0 { point.value / max * 100.0 } else { 0.0 };
+                    view! {
+                        <li class="space-y-1 text-sm">
+                            <div class="flex justify-between gap-2">
+                                <span class="truncate capitalize">{point.label}</span>
+                                <span class="tabular-nums text-muted-foreground">{format(point.value)}</span>
+                            </div>
+                            <div class="h-2 rounded-full bg-muted">
+                                <div
+                                    class=cn!("h-full rounded-full", CHART_BACKGROUNDS[index % CHART_BACKGROUNDS.len()])
+                                    style=format!("width: {width:.1}%")
+                                ></div>
+                            </div>
+                        </li>
+                    }
+                }).collect_view()
+            }}
+        </ul>
+    }
+}
+
+/// Vertical columns for a series over time, drawn as an SVG.
+#[component]
+pub fn ColumnChart(
+    #[prop(into)] points: Signal<Vec<ChartPoint>>,
+    /// Accessible name of the chart
+    #[prop(into)] label: String,
+    /// Index into the `--chart-N` tokens
+    #[prop(optional)] color: usize,
+    #[prop(optional, into)] class: String,
+) -> impl IntoView {
+    let fill = CHART_FILLS[color % CHART_FILLS.len()];
+
+    view! {
+        <div class=cn!("overflow-x-auto", class)>
+            {move || {
+                let points = points.get();
+                let max = max_value(&points);
+                let width = points.len() as f64 * (COLUMN_WIDTH + COLUMN_GAP) + COLUMN_GAP;
+                // Room for the value above and the label below each column
+                let view_box = format!("0 0 {width} {}", COLUMN_CHART_HEIGHT + 40.0);
+                view! {
+                    <svg viewBox=view_box role="img" aria-label=label.clone() class="h-52 min-w-full" preserveAspectRatio="xMinYMid meet">
+                        {points.into_iter().enumerate().map(|(index, point)| {
+                            let height = if max > 0.0 { point.value / max * COLUMN_CHART_HEIGHT } else { 0.0 };
+                            let x = COLUMN_GAP + index as f64 * (COLUMN_WIDTH + COLUMN_GAP);
+                            let y = 20.0 + COLUMN_CHART_HEIGHT - height;
+                            let center = x + COLUMN_WIDTH / 2.0;
+                            view! {
+                                <g>
+                                    <title>{format!("{}: {}", point.label, point.value)}</title>
+                                    <rect x=x y=y width=COLUMN_WIDTH height=height rx="3" class=fill />
+                                    <text x=center y=y - 6.0 text-anchor="middle" class="fill-muted-foreground text-[10px]">
+                                        {point.value.to_string()}
+                                    </text>
+                                    <text x=center y=COLUMN_CHART_HEIGHT + 36.0 text-anchor="middle" class="fill-muted-foreground text-[10px]">
+                                        {point.label}
+                                    </text>
+                                </g>
+                            }
+                        }).collect_view()}
+                    </svg>
+                }
+            }}
+        </div>
+    }
+}
@@ -0,0 +1,55 @@
+use crate::components::base::button::{Button, ButtonVariant};
+use leptos::prelude::*;
+
+#[component]
+pub fn ConfirmDialog(
+    #[prop(into)] open: Signal<bool>,
+    #[prop(into)] title: Signal<String>,
+    #[prop(into)] description: Signal<String>,
+    #[prop(optional, into, default = String::from("Delete"))] confirm_label: String,
+    #[prop(optional, into, default = Signal::derive(|| false))] pending: Signal<bool>,
+    #[prop(into)] on_confirm: Callback<()>,
+    #[prop(into)] on_cancel: Callback<()>,
+) -> impl IntoView {
+    view! {
+        <Show when=move || open.get()>
+            <div
+                class="fixed inset-0 z-50 flex items-center justify-center bg-black/40 px-4 animate-fade-in"
+                on:click=move |_| on_cancel.run(())
+                on:keydown=move |ev| if ev.key() == "Escape" { on_cancel.run(()) }
+            >
+                <div
+                    role="alertdialog"
+                    aria-modal="true"
+                    aria-labelledby="confirm-dialog-title"
+                    aria-describedby="confirm-dialog-description"
+                    class="w-full max-w-md rounded-lg border bg-background p-6 shadow-lg"
+                    on:click=|ev| ev.stop_propagation()
+                >
+                    <h2 id="confirm-dialog-title" class="text-lg font-semibold">{move || title.get()}</h2>
+                    <p id="confirm-dialog-description" class="mt-2 text-sm text-muted-foreground">
+                        {move || description.get()}
+                    </p>
+                    <div class="mt-6 flex justify-end gap-2">
+                        <Button
+                            variant=ButtonVariant::Outline
+                            on_click=move || on_cancel.run(())
+                            attr:disabled=move || pending.get()
+                            attr:autofocus=true
+                        >
+                            "Cancel"
+                        </Button>
+                        <Button
+                            variant=ButtonVariant::Destructive
+                            on_click=move || on_confirm.run(())
+                            attr:disabled=move || pending.get()
+                        >
+                            {let confirm_label = confirm_label.clone();
+                            move || if pending.get() { "Working...".to_string() } else { confirm_label.clone() }}
+                        </Button>
+                    </div>
+                </div>
+            </div>
+        </Show>
+    }
+}
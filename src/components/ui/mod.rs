@@ -3,4 +3,18 @@
 pub mod public_navigation;
 pub mod public_footer;
 pub mod sidebar_navigation;
-pub mod private_header;
\ No newline at end of file
+pub mod private_header;
+pub mod add_to_cart_button;
+pub mod breadcrumbs;
+pub mod cart_drawer;
+pub mod charts;
+pub mod confirm_dialog;
+pub mod page_title;
+pub mod pagination;
+pub mod product_card;
+pub mod product_search;
+pub mod route_announcer;
+pub mod route_error_boundary;
+pub mod skeleton;
+pub mod status_page;
+pub mod toast;
\ No newline at end of file
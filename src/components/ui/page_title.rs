@@ -0,0 +1,22 @@
+use crate::components::ui::breadcrumbs::use_route_label;
+use crate::routes::use_current_route;
+use leptos::prelude::*;
+use leptos_meta::Title;
+
+const APP_NAME: &str = "Leptos + Tailwind";
+
+/// Keeps the document `<title>` in step with the current route.
+#[component]
+pub fn PageTitle() -> impl IntoView {
+    let current = use_current_route();
+
+    move || {
+        let label = current.get().map(use_route_label);
+        view! {
+            <Title
+                formatter=|text: String| format!("{text} | {APP_NAME}")
+                text=move || label.map_or_else(|| "Page not found".to_string(), |label| label.get())
+            />
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use crate::components::base::button::{Button, ButtonSize, ButtonVariant};
+use leptos::prelude::*;
+
+/// Number of pages needed for `total` items.
+pub fn page_count(total: usize, page_size: usize) -> usize {
+    total.div_ceil(page_size).max(1)
+}
+
+#[component]
+pub fn Pagination(
+    /// Zero based current page
+    page: RwSignal<usize>,
+    #[prop(into)] total: Signal<usize>,
+    page_size: usize,
+) -> impl IntoView {
+    let pages = Memo::new(move |_| page_count(total.get(), page_size));
+
+    // Keep the current page in range when the item count shrinks
+    Effect::new(move |_| {
+        if page.get() >= pages.get() {
+            page.set(pages.get() - 1);
+        }
+    });
+
+    view! {
+        <nav aria-label="Pagination" class="flex items-center justify-between gap-2 text-sm">
+            <p class="text-muted-foreground">
+                {move || {
+                    let total = total.get();
+                    let start = (page.get() * page_size).min(total);
+                    let end = (start + page_size).min(total);
+                    format!("Showing {}-{} of {}", if total == 0 { 0 } else { start + 1 }, end, total)
+                }}
+            </p>
+            <div class="flex items-center gap-1">
+                <Button
+                    variant=ButtonVariant::Outline
+                    size=ButtonSize::Sm
+                    on_click=move || page.update(|p| *p = p.saturating_sub(1))
+                    attr:disabled=move || page.get() == 0
+                >
+                    "Previous"
+                </Button>
+                {move || (0..pages.get()).map(|index| view! {
+                    <Button
+                        variant=if index == page.get() { ButtonVariant::Default } else { ButtonVariant::Ghost }
+                        size=ButtonSize::Sm
+                        on_click=move || page.set(index)
+                        attr:aria-current=move || (index == page.get()).then_some("page")
+                    >
+                        {index + 1}
+                    </Button>
+                }).collect_view()}
+                <Button
+                    variant=ButtonVariant::Outline
+                    size=ButtonSize::Sm
+                    on_click=move || page.update(|p| *p = (*p + 1).min(pages.get_untracked() - 1))
+                    attr:disabled=move || page.get() + 1 >= pages.get()
+                >
+                    "Next"
+                </Button>
+            </div>
+        </nav>
+    }
+}
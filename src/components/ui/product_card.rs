@@ -0,0 +1,49 @@
+use crate::api::product_api::Product;
+use crate::utils::currency::format_price;
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+/// Storefront card for a product. Without `href` the title is not a link,
+/// which is what the editor preview uses.
+#[component]
+pub fn ProductCard(
+    #[prop(into)] product: Signal<Product>,
+    #[prop(optional, into)] href: Option<String>,
+    /// Actions rendered under the price, like an add-to-cart button
+    #[prop(optional)] children: Option<ChildrenFn>,
+) -> impl IntoView {
+    let title = move || product.with(|product| product.title.clone());
+
+    view! {
+        <article class="group flex h-full flex-col rounded-lg border bg-card p-4 shadow-xs transition hover:shadow-md">
+            <div class="flex h-48 items-center justify-center overflow-hidden rounded-md bg-white">
+                {move || {
+                    let image = product.with(|product| product.image.clone());
+                    if image.is_empty() {
+                        view! { <span class="text-xs text-muted-foreground">"No image"</span> }.into_any()
+                    } else {
+                        view! {
+                            <img src=image alt=title() loading="lazy" class="h-full w-full object-contain transition group-hover:scale-105" />
+                        }.into_any()
+                    }
+                }}
+            </div>
+            <p class="mt-4 text-xs uppercase tracking-wide text-muted-foreground">
+                {move || product.with(|product| product.category.clone())}
+            </p>
+            {match href {
+                Some(href) => view! {
+                    <A href=href attr:class="mt-1 line-clamp-2 font-medium hover:underline">{title}</A>
+                }.into_any(),
+                None => view! { <h3 class="mt-1 line-clamp-2 font-medium">{title}</h3> }.into_any(),
+            }}
+            <p class="mt-2 line-clamp-2 text-sm text-muted-foreground">
+                {move || product.with(|product| product.description.clone())}
+            </p>
+            <div class="mt-auto flex items-center justify-between gap-2 pt-4">
+                <p class="text-lg font-semibold">{move || product.with(|product| format_price(product.price))}</p>
+                {children.map(|children| children())}
+            </div>
+        </article>
+    }
+}
@@ -0,0 +1,202 @@
+use crate::api::product_api::{Product, get_all_products};
+use crate::cn;
+use crate::routes::AppRoute;
+use crate::utils::currency::format_price;
+use leptos::{ev::KeyboardEvent, prelude::*};
+use leptos_router::hooks::use_navigate;
+use std::time::Duration;
+
+const DEBOUNCE: Duration = Duration::from_millis(250);
+const MAX_SUGGESTIONS: usize = 6;
+
+/// Products whose title or category contains `query`, ignoring case.
+pub fn search_products(products: &[Product], query: &str) -> Vec<Product> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Vec::new();
+    }
+    products
+        .iter()
+        .filter(|product| {
+            product.title.to_lowercase().contains(&query) || product.category.to_lowercase().contains(&query)
+        })
+        .cloned()
+        .collect()
+}
+
+pub fn search_href(query: &str) -> String {
+    format!("{}?q={}", AppRoute::Search.href(), String::from(js_sys::encode_uri_component(query.trim())))
+}
+
+/// Byte range of the first case-insensitive match of `query` in `text`.
+fn find_match(text: &str, query: &str) -> Option<(usize, usize)> {
+    let query = query.trim().to_lowercase();
+    let lower = text.to_lowercase();
+    // Lowercasing can change byte lengths outside ASCII, then offsets no longer line up
+    if query.is_empty() || lower.len() != text.len() {
+        return None;
+    }
+    lower.find(&query).map(|start| (start, start + query.len()))
+}
+
+/// `text` with the first match of `query` wrapped in `<mark>`.
+#[component]
+pub fn Highlight(#[prop(into)] text: String, #[prop(into)] query: String) -> impl IntoView {
+    match find_match(&text, &query) {
+        Some((start, end)) => view! {
+            {text[..start].to_string()}
+            <mark class="rounded-sm bg-yellow-200 text-inherit">{text[start..end].to_string()}</mark>
+            {text[end..].to_string()}
+        }
+        .into_any(),
+        None => text.into_any(),
+    }
+}
+
+/// Navbar search box with a debounced type-ahead list. Enter opens the
+/// highlighted product, or the `/search` results page when none is.
+#[component]
+pub fn ProductSearch(#[prop(optional, into)] class: String) -> impl IntoView {
+    let navigate = use_navigate();
+    let products = LocalResource::new(|| async { get_all_products().await.unwrap_or_default() });
+
+    let (input, set_input) = signal(String::new());
+    // Trails `input` by `DEBOUNCE`, suggestions are computed from this one
+    let (query, set_query) = signal(String::new());
+    let (open, set_open) = signal(false);
+    let (active, set_active) = signal(None::<usize>);
+    let debounce = StoredValue::new(None::<TimeoutHandle>);
+
+    let suggestions = Memo::new(move |_| {
+        let mut matches = products.with(|products| {
+            products.as_deref().map(|products| search_products(products, &query.get())).unwrap_or_default()
+        });
+        matches.truncate(MAX_SUGGESTIONS);
+        matches
+    });
+    let show_list = move || open.get() && !query.with(|query| query.trim().is_empty());
+
+    let handle_input = move |value: String| {
+        set_input.set(value.clone());
+        set_open.set(true);
+        set_active.set(None);
+        if let Some(handle) = debounce.get_value() {
+            handle.clear();
+        }
+        let handle = set_timeout_with_handle(move || set_query.set(value), DEBOUNCE).ok();
+        debounce.set_value(handle);
+    };
+
+    let go_to = move |href: String| {
+        set_open.set(false);
+        set_active.set(None);
+        navigate(&href, Default::default());
+    };
+
+    let handle_keydown = {
+        let go_to = go_to.clone();
+        move |ev: KeyboardEvent| {
+            let count = suggestions.with(Vec::len);
+            match ev.key().as_str() {
+                "ArrowDown" if count > 0 => {
+                    ev.prevent_default();
+                    set_open.set(true);
+                    set_active.update(|active| *active = Some(active.map_or(0, |index| (index + 1) % count)));
+                }
+                "ArrowUp" if count > 0 => {
+                    ev.prevent_default();
+                    set_active.update(|active| *active = Some(active.map_or(count - 1, |index| (index + count - 1) % count)));
+                }
+                "Enter" => {
+                    ev.prevent_default();
+                    let selected = active
+                        .get_untracked()
+                        .and_then(|index| suggestions.with_untracked(|list| list.get(index).map(|product| product.id)));
+                    match selected {
+                        Some(product_id) => go_to(AppRoute::ShopProduct { id: product_id }.href()),
+                        None if !input.get_untracked().trim().is_empty() => go_to(search_href(&input.get_untracked())),
+                        None => {}
+                    }
+                }
+                "Escape" => {
+                    set_open.set(false);
+                    set_active.set(None);
+                }
+                _ => {}
+            }
+        }
+    };
+
+    view! {
+        <div class=cn!("relative", class)>
+            <div class="flex items-center text-sm gap-2 border border-gray-300 px-3 rounded-full">
+                <input
+                    type="search"
+                    role="combobox"
+                    aria-label="Search products"
+                    aria-autocomplete="list"
+                    aria-controls="product-search-list"
+                    aria-expanded=move || show_list().to_string()
+                    aria-activedescendant=move || active.get().map(|index| format!("product-search-option-{index}"))
+                    class="py-1.5 w-full bg-transparent outline-none placeholder-gray-500"
+                    placeholder="Search products"
+                    prop:value=move || input.get()
+                    on:input=move |ev| handle_input(event_target_value(&ev))
+                    on:keydown=handle_keydown
+                    on:focus=move |_| set_open.set(true)
+                    on:blur=move |_| set_open.set(false)
+                />
+                <svg width="16" height="16" viewBox="0 0 16 16" fill="none" xmlns="http://www.w3.org/2000/svg" aria-hidden="true">
+                    <path d="M10.836 10.615 15 14.695" stroke="#7A7B7D" stroke-width="1.2" stroke-linecap="round" stroke-linejoin="round"/>
+                    <path clip-rule="evenodd" d="M9.141 11.738c2.729-1.136 4.001-4.224 2.841-6.898S7.67.921 4.942 2.057C2.211 3.193.94 6.281 2.1 8.955s4.312 3.92 7.041 2.783" stroke="#7A7B7D" stroke-width="1.2" stroke-linecap="round" stroke-linejoin="round"/>
+                </svg>
+            </div>
+
+            <Show when=show_list>
+                <ul
+                    id="product-search-list"
+                    role="listbox"
+                    aria-label="Product suggestions"
+                    class="absolute right-0 top-full z-40 mt-2 w-96 max-w-[90vw] overflow-hidden rounded-lg border bg-white py-1 text-sm shadow-lg"
+                    // Keep focus in the input so the list is not closed by blur before a click lands
+                    on:mousedown=|ev| ev.prevent_default()
+                >
+                    {
+                        let go_to = go_to.clone();
+                        move || {
+                            let list = suggestions.get();
+                            if list.is_empty() {
+                                return view! { <li class="px-4 py-3 text-muted-foreground">"No matching products."</li> }.into_any();
+                            }
+                            let query = query.get();
+                            list.into_iter().enumerate().map(|(index, product)| {
+                                let go_to = go_to.clone();
+                                let href = AppRoute::ShopProduct { id: product.id }.href();
+                                let is_active = move || active.get() == Some(index);
+                                view! {
+                                    <li
+                                        id=format!("product-search-option-{index}")
+                                        role="option"
+                                        aria-selected=move || is_active().to_string()
+                                        class=move || cn!("flex cursor-pointer items-center gap-3 px-4 py-2", if is_active() { "bg-accent" } else { "" })
+                                        on:mouseenter=move |_| set_active.set(Some(index))
+                                        on:click=move |_| go_to(href.clone())
+                                    >
+                                        <img src=product.image alt="" class="size-8 shrink-0 object-contain" />
+                                        <div class="min-w-0 flex-1">
+                                            <p class="truncate font-medium"><Highlight text=product.title query=query.clone() /></p>
+                                            <p class="truncate text-xs capitalize text-muted-foreground">
+                                                <Highlight text=product.category query=query.clone() />
+                                            </p>
+                                        </div>
+                                        <span class="tabular-nums">{format_price(product.price)}</span>
+                                    </li>
+                                }
+                            }).collect_view().into_any()
+                        }
+                    }
+                </ul>
+            </Show>
+        </div>
+    }
+}
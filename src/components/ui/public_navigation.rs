@@ -1,28 +1,149 @@
-use leptos::prelude::*;
+use leptos::{ev, html, prelude::*, wasm_bindgen::JsCast, web_sys};
 use leptos_router::components::A;
+use leptos_router::hooks::use_location;
+use leptos_use::use_event_listener;
+
+use crate::cart::guest::use_guest_cart;
+use crate::cn;
+use crate::components::ui::product_search::ProductSearch;
+use crate::routes::{AppRoute, NavPlacement};
+
+const FOCUSABLE: &str = "a[href], button:not([disabled]), input:not([disabled])";
+
+fn focus(element: &web_sys::Element) {
+    if let Some(element) = element.dyn_ref::<web_sys::HtmlElement>() {
+        let _ = element.focus();
+    }
+}
 
 #[component]
 pub fn PublicNavigation() -> impl IntoView {
+    let cart = use_guest_cart();
+    let location = use_location();
+    let (open, set_open) = signal(false);
+    let nav_ref = NodeRef::<html::Nav>::new();
+    let panel_ref = NodeRef::<html::Div>::new();
+    let toggle_ref = NodeRef::<html::Button>::new();
+
+    let focusables = move || -> Vec<web_sys::Element> {
+        let Some(panel) = panel_ref.get_untracked() else { return Vec::new() };
+        let Ok(nodes) = panel.query_selector_all(FOCUSABLE) else { return Vec::new() };
+        (0..nodes.length())
+            .filter_map(|index| nodes.item(index))
+            .filter_map(|node| node.dyn_into::<web_sys::Element>().ok())
+            .collect()
+    };
+
+    let close = move |return_focus: bool| {
+        set_open.set(false);
+        if return_focus && let Some(toggle) = toggle_ref.get_untracked() {
+            let _ = toggle.focus();
+        }
+    };
+
+    // Move focus into the menu once it is visible
+    Effect::new(move |_| {
+        if open.get() {
+            request_animation_frame(move || {
+                if let Some(first) = focusables().first() {
+                    focus(first);
+                }
+            });
+        }
+    });
+
+    // Following a link closes the menu
+    Effect::new(move |previous: Option<String>| {
+        let path = location.pathname.get();
+        if previous.is_some_and(|previous| previous != path) {
+            set_open.set(false);
+        }
+        path
+    });
+
+    let _ = use_event_listener(document(), ev::keydown, move |event| {
+        if open.get_untracked() && event.key() == "Escape" {
+            close(true);
+        }
+    });
+
+    let _ = use_event_listener(document(), ev::click, move |event| {
+        if !open.get_untracked() {
+            return;
+        }
+        let inside = match (nav_ref.get_untracked(), event.target().and_then(|target| target.dyn_into::<web_sys::Node>().ok())) {
+            (Some(nav), Some(target)) => nav.contains(Some(&target)),
+            _ => false,
+        };
+        if !inside {
+            close(false);
+        }
+    });
+
+    // Keeps Tab and Shift+Tab cycling inside the open menu
+    let trap_focus = move |event: ev::KeyboardEvent| {
+        if event.key() != "Tab" {
+            return;
+        }
+        let items = focusables();
+        let (Some(first), Some(last)) = (items.first(), items.last()) else { return };
+        let active = document().active_element();
+        if event.shift_key() && active.as_ref() == Some(first) {
+            event.prevent_default();
+            focus(last);
+        } else if !event.shift_key() && active.as_ref() == Some(last) {
+            event.prevent_default();
+            focus(first);
+        }
+    };
+
     view! {
-        <nav class="w-full flex items-center justify-between px-6 md:px-16 lg:px-24 xl:px-32 py-4 border-b border-gray-300 bg-white relative transition-all">
-            <A href="/">
+        <nav node_ref=nav_ref class="w-full flex items-center justify-between px-6 md:px-16 lg:px-24 xl:px-32 py-4 border-b border-gray-300 bg-white relative transition-all">
+            <A href=AppRoute::Home.href()>
                 <img class="h-9" src="https://raw.githubusercontent.com/prebuiltui/prebuiltui/main/assets/dummyLogo/dummyLogoColored.svg" alt="dummyLogoColored" />
             </A>
 
-            <button aria-label="Menu" id="menu-toggle" class="sm:hidden">
-                <svg width="21" height="15" viewBox="0 0 21 15" fill="none" xmlns="http://www.w3.org/2000/svg">
+            <button
+                id="menu-toggle"
+                class="sm:hidden"
+                node_ref=toggle_ref
+                aria-label=move || if open.get() { "Close menu" } else { "Open menu" }
+                aria-expanded=move || open.get().to_string()
+                aria-controls="mobile-menu"
+                on:click=move |_| set_open.update(|open| *open = !*open)
+            >
+                <svg width="21" height="15" viewBox="0 0 21 15" fill="none" xmlns="http://www.w3.org/2000/svg" aria-hidden="true">
                     <rect width="21" height="1.5" rx=".75" fill="#426287"/>
                     <rect x="8" y="6" width="13" height="1.5" rx=".75" fill="#426287"/>
                     <rect x="6" y="13" width="15" height="1.5" rx=".75" fill="#426287"/>
                 </svg>
             </button>
 
-            <div id="mobile-menu" class="hidden absolute top-[60px] left-0 w-full bg-white shadow-md py-4 flex-col items-start gap-2 px-5 text-sm md:hidden">
-                <A href="/" attr:class="block">"Home"</A>
-                <A href="/" attr:class="block">"About"</A>
-                <A href="/" attr:class="block">"Contact"</A>
+            // Always mounted so closing can animate, `invisible` takes the links out of the tab order
+            <div
+                id="mobile-menu"
+                node_ref=panel_ref
+                aria-label="Mobile navigation"
+                class=move || cn!(
+                    "absolute top-[60px] left-0 z-30 w-full bg-white shadow-md py-4 flex flex-col items-start gap-2 px-5 text-sm sm:hidden transition-all duration-200 ease-out",
+                    if open.get() { "visible opacity-100 translate-y-0" } else { "invisible pointer-events-none opacity-0 -translate-y-2" }
+                )
+                on:keydown=trap_focus
+            >
+                {AppRoute::placed(NavPlacement::Navbar)
+                    .map(|route| view! { <A href=route.href() attr:class="block">{route.title()}</A> })
+                    .collect_view()}
+                <button
+                    class="block"
+                    on:click=move |_| {
+                        set_open.set(false);
+                        cart.open_drawer();
+                    }
+                >
+                    {move || format!("Cart ({})", cart.item_count())}
+                </button>
                 <A
-                    href="/login"
+                    href=AppRoute::Login.href()
                     attr:class="cursor-pointer px-6 py-2 mt-2 bg-indigo-500 hover:bg-indigo-600 transition text-white rounded-full text-sm"
                 >
                     "Login"
@@ -30,27 +151,29 @@ pub fn PublicNavigation() -> impl IntoView {
             </div>
 
             <div class="hidden sm:flex items-center gap-8">
-                <A href="/">"Home"</A>
-                <A href="/">"About"</A>
-                <A href="/">"Contact"</A>
-
-                <div class="hidden lg:flex items-center text-sm gap-2 border border-gray-300 px-3 rounded-full">
-                    <input class="py-1.5 w-full bg-transparent outline-none placeholder-gray-500" type="text" placeholder="Search products" />
-                    <svg width="16" height="16" viewBox="0 0 16 16" fill="none" xmlns="http://www.w3.org/2000/svg">
-                        <path d="M10.836 10.615 15 14.695" stroke="#7A7B7D" stroke-width="1.2" stroke-linecap="round" stroke-linejoin="round"/>
-                        <path clip-rule="evenodd" d="M9.141 11.738c2.729-1.136 4.001-4.224 2.841-6.898S7.67.921 4.942 2.057C2.211 3.193.94 6.281 2.1 8.955s4.312 3.92 7.041 2.783" stroke="#7A7B7D" stroke-width="1.2" stroke-linecap="round" stroke-linejoin="round"/>
-                    </svg>
-                </div>
+                {AppRoute::placed(NavPlacement::Navbar)
+                    .map(|route| view! { <A href=route.href()>{route.title()}</A> })
+                    .collect_view()}
 
-                <div class="relative cursor-pointer">
-                    <svg width="18" height="18" viewBox="0 0 14 14" fill="none" xmlns="http://www.w3.org/2000/svg">
+                <ProductSearch class="hidden lg:block" />
+
+                <button
+                    class="relative cursor-pointer"
+                    aria-label=move || format!("Open cart, {} item(s)", cart.item_count())
+                    on:click=move |_| cart.open_drawer()
+                >
+                    <svg width="18" height="18" viewBox="0 0 14 14" fill="none" xmlns="http://www.w3.org/2000/svg" aria-hidden="true">
                         <path d="M.583.583h2.333l1.564 7.81a1.17 1.17 0 0 0 1.166.94h5.67a1.17 1.17 0 0 0 1.167-.94l.933-4.893H3.5m2.333 8.75a.583.583 0 1 1-1.167 0 .583.583 0 0 1 1.167 0m6.417 0a.583.583 0 1 1-1.167 0 .583.583 0 0 1 1.167 0" stroke="#615fff" stroke-linecap="round" stroke-linejoin="round"/>
                     </svg>
-                    <button class="absolute -top-2 -right-3 text-xs text-white bg-indigo-500 w-[18px] h-[18px] rounded-full">"3"</button>
-                </div>
+                    <Show when=move || cart.item_count() > 0>
+                        <span class="absolute -top-2 -right-3 flex items-center justify-center text-xs text-white bg-indigo-500 min-w-[18px] h-[18px] px-1 rounded-full" aria-hidden="true">
+                            {move || cart.item_count()}
+                        </span>
+                    </Show>
+                </button>
 
                 <A
-                    href="/login"
+                    href=AppRoute::Login.href()
                     attr:class="cursor-pointer px-8 py-2 bg-indigo-500 hover:bg-indigo-600 transition text-white rounded-full"
                 >
                     "Login"
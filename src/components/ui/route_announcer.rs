@@ -0,0 +1,80 @@
+use crate::utils::scroll_restoration::use_scroll_restoration;
+use leptos::{prelude::*, wasm_bindgen::JsCast, web_sys};
+use leptos_router::hooks::use_location;
+
+/// Id of the `<main>` element in both layouts, the skip target.
+pub const MAIN_CONTENT_ID: &str = "main-content";
+
+/// Takes care of what the browser no longer does for client-side
+/// navigations: scroll position (see `use_scroll_restoration`), moving
+/// focus to the new page and telling screen readers about it.
+///
+/// After each navigation focus goes to the page's `<h1>`, or to `<main>`
+/// while the page is still loading, and the document title is announced
+/// in a polite live region.
+#[component]
+pub fn RouteAnnouncer() -> impl IntoView {
+    use_scroll_restoration();
+
+    let pathname = use_location().pathname;
+    let (message, set_message) = signal(String::new());
+
+    Effect::new(move |previous: Option<String>| {
+        let path = pathname.get();
+        if previous.is_some_and(|previous| previous != path) {
+            // Wait a frame for the new page and its `<Title>` to render
+            request_animation_frame(move || {
+                let heading = focus_main_content();
+                let title = document().title();
+                set_message.set(format!(
+                    "Navigated to {}",
+                    if title.is_empty() { heading.unwrap_or_default() } else { title }
+                ));
+            });
+        }
+        path
+    });
+
+    view! {
+        <div class="sr-only" role="status" aria-live="polite" aria-atomic="true">
+            {message}
+        </div>
+    }
+}
+
+/// Moves focus to the `<h1>` inside `<main>`, or `<main>` itself, without
+/// scrolling. Returns the heading text when there was one.
+pub fn focus_main_content() -> Option<String> {
+    let main = document().get_element_by_id(MAIN_CONTENT_ID)?;
+    let heading = main.query_selector("h1").ok().flatten();
+    let target = heading.clone().unwrap_or_else(|| main.clone());
+    if !target.has_attribute("tabindex") {
+        let _ = target.set_attribute("tabindex", "-1");
+    }
+
+    let options = web_sys::FocusOptions::new();
+    options.set_prevent_scroll(true);
+    if let Ok(target) = target.dyn_into::<web_sys::HtmlElement>() {
+        let _ = target.focus_with_options(&options);
+    }
+
+    heading.and_then(|heading| heading.text_content()).map(|text| text.trim().to_string())
+}
+
+/// First focusable element of a layout, visible only when focused.
+#[component]
+pub fn SkipLink() -> impl IntoView {
+    view! {
+        <a
+            href=format!("#{MAIN_CONTENT_ID}")
+            class="sr-only focus:not-sr-only focus:fixed focus:left-4 focus:top-4 focus:z-50 focus:rounded-md focus:bg-background focus:px-4 focus:py-2 focus:text-sm focus:font-medium focus:shadow-md focus:ring-2 focus:ring-ring/50"
+            on:click=move |ev| {
+                // Keep the router from treating the fragment as a navigation
+                ev.prevent_default();
+                focus_main_content();
+            }
+        >
+            "Skip to main content"
+        </a>
+    }
+}
@@ -0,0 +1,68 @@
+use crate::components::ui::status_page::StatusPage;
+use crate::routes::AppRoute;
+use leptos::prelude::*;
+use leptos_router::components::A;
+use std::fmt;
+
+/// Data a page can't render without failed to load. Data pages return it
+/// from their `Suspend` so `RouteErrorBoundary` replaces the page.
+#[derive(Clone, Debug)]
+pub struct LoadError {
+    what: &'static str,
+    detail: String,
+}
+
+impl LoadError {
+    pub fn new(what: &'static str, detail: impl Into<String>) -> Self {
+        Self { what, detail: detail.into() }
+    }
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Could not load {}: {}", self.what, self.detail)
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+/// Catches errors rendered by the current page, such as a `LoadError`, and
+/// shows an error page in its place. Retry remounts the page, which re-runs
+/// its resources; failed responses are never cached so they are fetched again.
+///
+/// Panics abort the WASM module and can't be caught here, they are only
+/// logged by `console_error_panic_hook`.
+#[component]
+pub fn RouteErrorBoundary(#[prop(optional, into)] class: String, children: ChildrenFn) -> impl IntoView {
+    let attempt = RwSignal::new(0u32);
+    let class = StoredValue::new(class);
+
+    view! {
+        <ErrorBoundary fallback=move |errors| {
+            let message = errors
+                .with(|errors| errors.iter().next().map(|(_, error)| error.to_string()))
+                .unwrap_or_else(|| "Something went wrong while loading this page.".to_string());
+            view! {
+                <StatusPage code="500" title="Something Went Wrong" message=message class=class.get_value()>
+                    <button
+                        class="bg-gray-800 hover:bg-black px-7 py-2.5 text-white rounded-md active:scale-95 transition-all"
+                        on:click=move |_| {
+                            attempt.update(|attempt| *attempt += 1);
+                            errors.update(|errors| errors.clear());
+                        }
+                    >
+                        "Try again"
+                    </button>
+                    <A href=AppRoute::Home.href() attr:class="border border-gray-300 px-7 py-2.5 text-gray-800 rounded-md active:scale-95 transition-all">
+                        "Return Home"
+                    </A>
+                </StatusPage>
+            }
+        }>
+            {move || {
+                attempt.track();
+                children()
+            }}
+        </ErrorBoundary>
+    }
+}
@@ -3,8 +3,32 @@ use crate::components::base::sidebar::{
     Sidebar, SidebarCollapsible, SidebarContent, SidebarFooter, SidebarHeader, SidebarVariant,
 };
 use crate::components::base::icons::*;
+use crate::routes::{AppRoute, NavPlacement};
+use crate::session::context::use_session;
 use leptos::prelude::*;
 use leptos_router::components::A;
+use leptos_router::hooks::use_location;
+
+/// How a nav item's url is compared with the current path.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum MatchMode {
+    /// Active only on the url itself
+    #[default]
+    Exact,
+    /// Also active on nested paths, `/users` matches `/users/3/edit`
+    Prefix,
+}
+
+impl MatchMode {
+    pub fn matches(&self, url: &str, path: &str) -> bool {
+        match self {
+            MatchMode::Exact => path == url,
+            MatchMode::Prefix => path
+                .strip_prefix(url.trim_end_matches('/'))
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('/')),
+        }
+    }
+}
 
 // Navigation item structures
 #[derive(Clone)]
@@ -12,10 +36,39 @@ pub struct NavItem {
     pub title: &'static str,
     pub icon: Option<Icon>,
     pub url: Option<&'static str>,
+    pub match_mode: MatchMode,
     pub permissions: Vec<&'static str>,
     pub items: Option<Vec<NavItem>>,
 }
 
+impl NavItem {
+    fn link(route: AppRoute, match_mode: MatchMode) -> Self {
+        Self {
+            title: route.title(),
+            icon: route.icon(),
+            url: Some(route.pattern()),
+            match_mode,
+            permissions: route.permission().into_iter().collect(),
+            items: None,
+        }
+    }
+
+    fn is_active(&self, path: &str) -> bool {
+        self.url.is_some_and(|url| self.match_mode.matches(url, path))
+    }
+}
+
+/// Url of the most specific sibling matching `path`. A `Prefix` item such
+/// as `/products` also matches `/products/new`, so the longest match wins
+/// and only the `Exact` `/products/new` item is highlighted.
+fn active_url(items: &[NavItem], path: &str) -> Option<&'static str> {
+    items
+        .iter()
+        .filter(|item| item.is_active(path))
+        .filter_map(|item| item.url)
+        .max_by_key(|url| url.len())
+}
+
 #[derive(Clone)]
 pub struct NavGroup {
     pub label: &'static str,
@@ -27,56 +80,34 @@ pub struct SidebarNavConfig {
 }
 
 impl SidebarNavConfig {
+    /// Built from the route table, in table order.
     pub fn new() -> Self {
+        let mut items: Vec<NavItem> = Vec::new();
+        for route in AppRoute::static_routes() {
+            for placement in route.nav() {
+                match *placement {
+                    NavPlacement::Sidebar(match_mode) => items.push(NavItem::link(route, match_mode)),
+                    NavPlacement::SidebarGroup(group, match_mode) => {
+                        let link = NavItem { icon: None, ..NavItem::link(route, match_mode) };
+                        match items.iter_mut().find(|item| item.url.is_none() && item.title == group.title()) {
+                            Some(parent) => parent.items.get_or_insert_with(Vec::new).push(link),
+                            None => items.push(NavItem {
+                                title: group.title(),
+                                icon: Some(group.icon()),
+                                url: None,
+                                match_mode: MatchMode::Exact,
+                                permissions: Vec::new(),
+                                items: Some(vec![link]),
+                            }),
+                        }
+                    }
+                    NavPlacement::Navbar => {}
+                }
+            }
+        }
+
         Self {
-            groups: vec![NavGroup {
-                label: "",
-                items: vec![
-                    NavItem {
-                        title: "Home",
-                        icon: Some(Icon::HOME.clone()),
-                        url: Some("/"),
-                        permissions: vec![""],
-                        items: None,
-                    },
-                    NavItem {
-                        title: "Create User",
-                        icon: Some(Icon::USER.clone()),
-                        url: Some("/users"),
-                        permissions: vec![""],
-                        items: None,
-                    },
-                    NavItem {
-                        title: "Products",
-                        icon: Some(Icon::PRODUCTS.clone()),
-                        url: None,
-                        permissions: vec![""],
-                        items: Some(vec![
-                            NavItem {
-                                title: "products",
-                                url: Some("/products"),
-                                permissions: vec![""],
-                                icon: None,
-                                items: None,
-                            },
-                            NavItem {
-                                title: "Create New Product",
-                                url: Some("/products/create-new"),
-                                permissions: vec![""],
-                                icon: None,
-                                items: None,
-                            },
-                            NavItem {
-                                title: "Update a Product",
-                                url: Some("/products/update-product"),
-                                permissions: vec![""],
-                                icon: None,
-                                items: None,
-                            },
-                        ]),
-                    },
-                ],
-            }],
+            groups: vec![NavGroup { label: "", items }],
         }
     }
 }
@@ -84,16 +115,13 @@ impl SidebarNavConfig {
 #[component]
 pub fn SidebarNavigation() -> impl IntoView {
     let nav_config = SidebarNavConfig::new();
-    let user_permissions: &[&str] = &[""];
+    let session = use_session();
+    let active_path = use_location().pathname;
 
-    // Get current path (simplified - you'd use your router's current path)
-    let (active_path, _) = signal("/");
-
-    // Helper function to check permissions
-    let has_permission = |required_permissions: &[&str], user_permissions: &[&str]| {
+    let has_permission = move |required_permissions: &[&str]| {
         required_permissions
             .iter()
-            .all(|permission| user_permissions.contains(permission))
+            .all(|permission| session.has_permission(permission))
     };
 
     view! {
@@ -133,24 +161,28 @@ pub fn SidebarNavigation() -> impl IntoView {
 
                             <div class="space-y-1">
                                 {group.items.into_iter()
-                                    .filter(|item| has_permission(&item.permissions, user_permissions))
+                                    .filter(|item| has_permission(&item.permissions))
                                     .map(|item| {
                                         let item_clone = item.clone();
 
                                         if let Some(sub_items) = item.items {
                                             // Create a reactive signal for parent active state
                                             let items_clone = sub_items.clone();
-                                            let is_parent_active = Signal::derive(move || {
-                                                items_clone.iter().any(|sub_item| {
-                                                    sub_item.url.as_ref().map_or(false, |u| active_path.get() == *u)
-                                                })
+                                            let active_sub_url = Memo::new(move |_| {
+                                                active_path.with(|path| active_url(&items_clone, path))
                                             });
+                                            let is_parent_active = Signal::derive(move || active_sub_url.get().is_some());
 
-                                            // Get initial value for default_open
-                                            let default_open = is_parent_active.get_untracked();
+                                            // Expand the group whenever navigation lands on one of its items
+                                            let expanded = RwSignal::new(is_parent_active.get_untracked());
+                                            Effect::new(move |_| {
+                                                if is_parent_active.get() {
+                                                    expanded.set(true);
+                                                }
+                                            });
 
                                             view! {
-                                                <Collapsible default_open=default_open class="group/collapsible ml-0".to_string()>
+                                                <Collapsible open=expanded class="group/collapsible ml-0".to_string()>
                                                     <CollapsibleTrigger as_child=true>
                                                         <div class=move || {
                                                             let base_classes = "flex items-center justify-between p-2 rounded-md hover:bg-muted transition-colors cursor-pointer w-full";
@@ -174,16 +206,17 @@ pub fn SidebarNavigation() -> impl IntoView {
                                                     <CollapsibleContent class="group-data-[collapsible=icon]:hidden">
                                                         <div class="ml-4 pl-2 border-l border-border space-y-1 mt-1">
                                                             {sub_items.into_iter()
-                                                                .filter(|sub_item| has_permission(&sub_item.permissions, user_permissions))
+                                                                .filter(|sub_item| has_permission(&sub_item.permissions))
                                                                 .map(|sub_item| {
                                                                     let sub_url = sub_item.url;
                                                                     let is_sub_active = Signal::derive(move || {
-                                                                        sub_url.map_or(false, |u| active_path.get() == u)
+                                                                        sub_url.is_some() && active_sub_url.get() == sub_url
                                                                     });
 
                                                                     view! {
                                                                         <A
                                                                             href=sub_item.url.unwrap_or("")
+                                                                            attr:aria-current=move || is_sub_active.get().then_some("page")
                                                                             attr:class=move || {
                                                                                 let base_classes = "flex items-center gap-2 p-2 rounded-md hover:bg-muted transition-colors";
                                                                                 let active_classes = if is_sub_active.get() {
@@ -204,14 +237,15 @@ pub fn SidebarNavigation() -> impl IntoView {
                                                 </Collapsible>
                                             }.into_any()
                                         } else {
-                                            let item_url = item.url;
+                                            let nav_item = item.clone();
                                             let is_item_active = Signal::derive(move || {
-                                                item_url.map_or(false, |u| active_path.get() == u)
+                                                active_path.with(|path| nav_item.is_active(path))
                                             });
 
                                             view! {
                                                 <A
                                                     href=item.url.unwrap_or("")
+                                                    attr:aria-current=move || is_item_active.get().then_some("page")
                                                     attr:class=move || {
                                                         let base_classes = "flex items-center gap-2 p-2 rounded-md hover:bg-muted transition-colors";
                                                         let active_classes = if is_item_active.get() {
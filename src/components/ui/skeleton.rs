@@ -0,0 +1,91 @@
+use crate::cn;
+use leptos::prelude::*;
+
+/// Pulsing placeholder block, the base of the other skeletons.
+///
+/// Data pages render skeletons as the `fallback` of a `Transition` around
+/// the resource: the first load shows the skeleton, later loads (refetch,
+/// new route params) keep the previous content on screen until they finish.
+#[component]
+pub fn Skeleton(#[prop(optional, into)] class: String) -> impl IntoView {
+    view! { <div aria-hidden="true" class=cn!("animate-pulse rounded-md bg-muted", class)></div> }
+}
+
+/// Lines of text, the last one shorter like the end of a paragraph.
+#[component]
+pub fn SkeletonText(#[prop(default = 1)] lines: usize, #[prop(optional, into)] class: String) -> impl IntoView {
+    view! {
+        <div class=cn!("space-y-2", class)>
+            {(0..lines).map(|line| view! {
+                <Skeleton class=if line + 1 == lines && lines > 1 { "h-4 w-2/3" } else { "h-4 w-full" } />
+            }).collect_view()}
+        </div>
+    }
+}
+
+#[component]
+pub fn SkeletonAvatar(#[prop(optional, into)] class: String) -> impl IntoView {
+    view! { <Skeleton class=cn!("size-10 shrink-0 rounded-full", class) /> }
+}
+
+/// Same footprint as `ProductCard`.
+#[component]
+pub fn SkeletonCard(#[prop(optional, into)] class: String) -> impl IntoView {
+    view! {
+        <div class=cn!("flex h-full flex-col gap-3 rounded-lg border bg-card p-4 shadow-xs", class)>
+            <Skeleton class="h-48 w-full" />
+            <Skeleton class="h-3 w-1/3" />
+            <SkeletonText lines=2 />
+            <Skeleton class="mt-auto h-8 w-24" />
+        </div>
+    }
+}
+
+#[component]
+pub fn SkeletonTableRow(columns: usize) -> impl IntoView {
+    view! {
+        <tr class="border-t">
+            {(0..columns).map(|_| view! {
+                <td class="px-4 py-3"><Skeleton class="h-4 w-full" /></td>
+            }).collect_view()}
+        </tr>
+    }
+}
+
+/// Bordered table of `rows` skeleton rows, announced as `label` to screen readers.
+#[component]
+pub fn SkeletonTable(
+    rows: usize,
+    columns: usize,
+    #[prop(into)] label: String,
+    #[prop(optional, into)] class: String,
+) -> impl IntoView {
+    view! {
+        <div class=cn!("overflow-x-auto rounded-md border", class) role="status">
+            <span class="sr-only">{label}</span>
+            <table class="w-full text-sm">
+                <thead class="bg-muted/50">
+                    <tr>
+                        {(0..columns).map(|_| view! {
+                            <th scope="col" class="px-4 py-3"><Skeleton class="h-3 w-16" /></th>
+                        }).collect_view()}
+                    </tr>
+                </thead>
+                <tbody>
+                    {(0..rows).map(|_| view! { <SkeletonTableRow columns=columns /> }).collect_view()}
+                </tbody>
+            </table>
+        </div>
+    }
+}
+
+/// Grid of `count` skeleton cards, announced as `label` to screen readers.
+#[component]
+pub fn SkeletonGrid(count: usize, #[prop(into)] label: String, #[prop(optional, into)] class: String) -> impl IntoView {
+    view! {
+        <div class=cn!("grid gap-6 sm:grid-cols-2 lg:grid-cols-3 xl:grid-cols-4", class) role="status">
+            <span class="sr-only">{label}</span>
+            {(0..count).map(|_| view! { <SkeletonCard /> }).collect_view()}
+        </div>
+    }
+}
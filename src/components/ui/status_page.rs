@@ -0,0 +1,25 @@
+use crate::cn;
+use leptos::prelude::*;
+
+/// Full page notice with a large status code, shared by the 404, 403 and
+/// error pages. `children` are the action buttons.
+#[component]
+pub fn StatusPage(
+    code: &'static str,
+    #[prop(into)] title: String,
+    #[prop(into)] message: String,
+    #[prop(optional, into)] class: String,
+    children: Children,
+) -> impl IntoView {
+    view! {
+        <div class=cn!("min-h-dvh w-full flex flex-col items-center justify-center text-sm max-md:px-4", class)>
+            <h1 class="text-8xl md:text-9xl font-bold text-indigo-500">{code}</h1>
+            <div class="h-1 w-16 rounded bg-indigo-500 my-5 md:my-7"></div>
+            <p class="text-2xl md:text-3xl font-bold text-gray-800">{title}</p>
+            <p class="text-sm md:text-base mt-4 text-gray-500 max-w-md text-center">{message}</p>
+            <div class="flex items-center gap-4 mt-6">
+                {children()}
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,104 @@
+use crate::session::logout::on_logout;
+use leptos::prelude::*;
+use std::time::Duration;
+
+const TOAST_DURATION: Duration = Duration::from_secs(4);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ToastKind {
+    Success,
+    Error,
+}
+
+#[derive(Clone, Debug)]
+struct Toast {
+    id: usize,
+    kind: ToastKind,
+    message: String,
+}
+
+#[derive(Copy, Clone)]
+pub struct Toaster {
+    toasts: RwSignal<Vec<Toast>>,
+    next_id: StoredValue<usize>,
+}
+
+impl Toaster {
+    pub fn show(&self, kind: ToastKind, message: impl Into<String>) {
+        let id = self.next_id.get_value();
+        self.next_id.set_value(id + 1);
+        self.toasts.update(|toasts| {
+            toasts.push(Toast {
+                id,
+                kind,
+                message: message.into(),
+            })
+        });
+
+        let toaster = *self;
+        set_timeout(move || toaster.dismiss(id), TOAST_DURATION);
+    }
+
+    pub fn success(&self, message: impl Into<String>) {
+        self.show(ToastKind::Success, message);
+    }
+
+    pub fn error(&self, message: impl Into<String>) {
+        self.show(ToastKind::Error, message);
+    }
+
+    pub fn dismiss(&self, id: usize) {
+        self.toasts.update(|toasts| toasts.retain(|toast| toast.id != id));
+    }
+}
+
+pub fn provide_toaster() -> Toaster {
+    let toaster = Toaster {
+        toasts: RwSignal::new(Vec::new()),
+        next_id: StoredValue::new(0),
+    };
+    provide_context(toaster);
+    // Notifications about the previous session's data are stale after logout
+    on_logout(move || toaster.toasts.set(Vec::new()));
+    toaster
+}
+
+pub fn use_toaster() -> Toaster {
+    expect_context::<Toaster>()
+}
+
+/// Renders the queued notifications, mount it once near the app root.
+#[component]
+pub fn Toasts() -> impl IntoView {
+    let toaster = use_toaster();
+
+    view! {
+        <div aria-live="polite" class="pointer-events-none fixed bottom-4 right-4 z-50 flex w-full max-w-sm flex-col gap-2">
+            <For
+                each=move || toaster.toasts.get()
+                key=|toast| toast.id
+                children=move |toast| {
+                    let kind_class = match toast.kind {
+                        ToastKind::Success => "border-green-200 bg-green-50 text-green-800",
+                        ToastKind::Error => "border-red-200 bg-red-50 text-red-800",
+                    };
+                    view! {
+                        <div
+                            role=if toast.kind == ToastKind::Error { "alert" } else { "status" }
+                            class=format!("pointer-events-auto flex items-start justify-between gap-3 rounded-md border px-4 py-3 text-sm shadow-md animate-slide-in-right {kind_class}")
+                        >
+                            <p>{toast.message}</p>
+                            <button
+                                class="cursor-pointer opacity-70 hover:opacity-100"
+                                aria-label="Dismiss notification"
+                                on:click=move |_| toaster.dismiss(toast.id)
+                            >
+                                "✕"
+                            </button>
+                        </div>
+                    }
+                }
+            />
+        </div>
+    }
+}
@@ -1,21 +1,35 @@
-use leptos::{prelude::*, server::codee::string::FromToStringCodec};
+use leptos::prelude::*;
 use leptos_router::{NavigateOptions, components::Outlet, hooks::use_navigate};
-use leptos_use::use_cookie;
 
 use crate::components::{
     base::sidebar::{SidebarInset, SidebarProvider},
-    ui::{private_header::PrivateHeader, sidebar_navigation::SidebarNavigation}
+    ui::{
+        private_header::PrivateHeader,
+        route_announcer::{MAIN_CONTENT_ID, SkipLink},
+        route_error_boundary::RouteErrorBoundary,
+        sidebar_navigation::SidebarNavigation,
+    },
 };
+use crate::pages::forbidden::Forbidden;
+use crate::routes::{AppRoute, use_current_route};
+use crate::session::context::use_session;
 
 #[component]
 pub fn PrivateLayout() -> impl IntoView {
     let navigate = use_navigate();
-    let (get_cookie, _) = use_cookie::<String, FromToStringCodec>("auth_token");
+    let session = use_session();
+    let current_route = use_current_route();
+    let allowed = move || {
+        current_route
+            .get()
+            .and_then(|route| route.permission())
+            .is_none_or(|permission| session.has_permission(permission))
+    };
 
     Effect::new(move |_| {
-        if get_cookie.get().is_none() {
+        if !session.is_authenticated() {
             navigate(
-                "/login",
+                &AppRoute::Login.href(),
                 NavigateOptions {
                     replace: true,
                     ..Default::default()
@@ -26,14 +40,19 @@ pub fn PrivateLayout() -> impl IntoView {
 
     view! {
         <Show
-            when=move || get_cookie.get().is_some()
+            when=move || session.is_authenticated()
             fallback=|| view! { <div>"Redirecting..."</div> }
         >
+            <SkipLink />
             <SidebarProvider>
                 <SidebarNavigation />
-                <SidebarInset>
+                <SidebarInset class="outline-none".to_string() attr:id=MAIN_CONTENT_ID attr:tabindex="-1">
                     <PrivateHeader />
-                    <Outlet />
+                    <RouteErrorBoundary class="min-h-[70vh]">
+                        <Show when=allowed fallback=Forbidden>
+                            <Outlet />
+                        </Show>
+                    </RouteErrorBoundary>
                 </SidebarInset>
             </SidebarProvider>
         </Show>
@@ -1,17 +1,27 @@
 use leptos::prelude::*;
 use leptos_router::components::Outlet;
 
-use crate::components::ui::{public_footer::PublicFooter, public_navigation::PublicNavigation};
+use crate::components::ui::{
+    cart_drawer::CartDrawer,
+    public_footer::PublicFooter,
+    public_navigation::PublicNavigation,
+    route_announcer::{MAIN_CONTENT_ID, SkipLink},
+    route_error_boundary::RouteErrorBoundary,
+};
 
 #[component]
 pub fn PublicLayout() -> impl IntoView {
     view! {
         <div class="min-h-dvh w-full flex flex-col items-center justify-center">
+            <SkipLink />
             <PublicNavigation />
-            <main class="flex-1 w-full">
-                <Outlet />
+            <main id=MAIN_CONTENT_ID tabindex="-1" class="flex-1 w-full outline-none">
+                <RouteErrorBoundary class="min-h-[70vh]">
+                    <Outlet />
+                </RouteErrorBoundary>
             </main>
             <PublicFooter />
+            <CartDrawer />
         </div>
     }
 }
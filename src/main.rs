@@ -1,10 +1,13 @@
 use leptos::mount::mount_to_body;
 
 mod app;
+mod cart;
 mod components;
 mod pages;
 mod api;
 mod layouts;
+mod routes;
+mod session;
 mod utils;
 
 use app::App;
@@ -0,0 +1,42 @@
+use crate::components::base::icons::*;
+use crate::routes::AppRoute;
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+#[component]
+fn Feature(icon: Icon, title: &'static str, text: &'static str) -> impl IntoView {
+    view! {
+        <div class="rounded-lg border bg-card p-5 shadow-xs">
+            <IconView icon=icon class="size-6 text-indigo-500" />
+            <h2 class="mt-3 font-semibold">{title}</h2>
+            <p class="mt-1 text-sm text-muted-foreground">{text}</p>
+        </div>
+    }
+}
+
+#[component]
+pub fn About() -> impl IntoView {
+    view! {
+        <section class="mx-auto max-w-4xl space-y-10 px-6 py-12">
+            <div class="space-y-3">
+                <h1 class="text-3xl font-semibold">"About us"</h1>
+                <p class="max-w-2xl text-muted-foreground">
+                    "We are a small online store selling clothing, jewellery and electronics. We pick every product for quality and keep prices fair, and we answer every message ourselves."
+                </p>
+            </div>
+
+            <div class="grid gap-4 sm:grid-cols-3">
+                <Feature icon=Icon::TRUCK title="Fast delivery" text="Standard shipping is free, express and next-day options are available at checkout." />
+                <Feature icon=Icon::PACKAGE_CHECK title="Easy returns" text="Changed your mind? Send items back within 30 days for a full refund." />
+                <Feature icon=Icon::CREDIT_CARD title="Secure payments" text="Card details go straight to our payment provider and are never stored." />
+            </div>
+
+            <div class="flex flex-wrap items-center gap-4 rounded-lg bg-muted/50 p-6">
+                <p class="flex-1 text-sm">"Have a question that isn't answered here?"</p>
+                <A href=AppRoute::Contact.href() attr:class="inline-flex h-9 items-center rounded-md bg-primary px-4 text-sm font-medium text-primary-foreground hover:bg-primary/90">
+                    "Contact support"
+                </A>
+            </div>
+        </section>
+    }
+}
@@ -0,0 +1,309 @@
+use crate::api::cart_api::{Cart, CartItem, delete_a_cart, get_a_cart, update_a_cart};
+use crate::api::product_api::Product;
+use crate::api::users_api::{User, get_a_user};
+use crate::components::base::button::{Button, ButtonSize, ButtonVariant};
+use crate::components::base::icons::*;
+use crate::components::ui::{
+    confirm_dialog::ConfirmDialog,
+    route_error_boundary::LoadError,
+    skeleton::{Skeleton, SkeletonTable},
+    toast::use_toaster,
+};
+use crate::pages::carts::{cart_total, format_cart_date, item_count, load_products_by_id};
+use crate::routes::AppRoute;
+use crate::utils::currency::format_price;
+use crate::utils::unsaved_changes::use_unsaved_changes_prompt;
+use leptos::prelude::*;
+use leptos_router::components::A;
+use leptos_router::hooks::{use_navigate, use_params_map};
+use std::collections::HashMap;
+
+#[derive(Clone, Debug)]
+struct CartDetailData {
+    cart: Cart,
+    products: HashMap<u32, Product>,
+    /// The cart is still shown when its owner cannot be loaded
+    user: Option<User>,
+}
+
+async fn load_cart(cart_id: u32) -> Result<CartDetailData, String> {
+    let cart = get_a_cart(cart_id).await.map_err(|e| e.to_string())?;
+    let (products, user) = futures::join!(load_products_by_id(), get_a_user(cart.user_id));
+    Ok(CartDetailData { cart, products: products?, user: user.ok() })
+}
+
+#[component]
+fn CartDetailSkeleton() -> impl IntoView {
+    view! {
+        <div class="space-y-6">
+            <div class="space-y-2">
+                <Skeleton class="h-8 w-40" />
+                <Skeleton class="h-4 w-56" />
+            </div>
+            <SkeletonTable rows=3 columns=4 label="Loading cart..." />
+        </div>
+    }
+}
+
+/// Cart drill-down at `/cart/:id`, with editable line quantities.
+#[component]
+pub fn CartDetail() -> impl IntoView {
+    let params = use_params_map();
+    let navigate = use_navigate();
+    let toaster = use_toaster();
+
+    let cart_id = Memo::new(move |_| params.read().get("id").and_then(|id| id.parse::<u32>().ok()));
+
+    let data = LocalResource::new(move || {
+        let cart_id = cart_id.get();
+        async move {
+            match cart_id {
+                Some(cart_id) => load_cart(cart_id).await,
+                None => Err("Invalid cart id.".to_string()),
+            }
+        }
+    });
+
+    let (items, set_items) = signal(Vec::<CartItem>::new());
+    // Last saved (or loaded) lines, the cart is dirty while they differ
+    let (initial, set_initial) = signal(Vec::<CartItem>::new());
+    let (confirm_open, set_confirm_open) = signal(false);
+
+    Effect::new(move |_| {
+        if let Some(Ok(data)) = data.get() {
+            set_initial.set(data.cart.products.clone());
+            set_items.set(data.cart.products);
+        }
+    });
+
+    let dirty = Signal::derive(move || items.get() != initial.get());
+    use_unsaved_changes_prompt(dirty);
+
+    let set_quantity = move |product_id: u32, quantity: u32| {
+        set_items.update(|items| {
+            if quantity == 0 {
+                items.retain(|item| item.product_id != product_id);
+            } else if let Some(item) = items.iter_mut().find(|item| item.product_id == product_id) {
+                item.quantity = quantity;
+            }
+        });
+    };
+
+    let save_action = Action::new_local(|cart: &Cart| {
+        let cart = cart.clone();
+        async move { update_a_cart(cart).await.map_err(|e| e.to_string()) }
+    });
+
+    Effect::new(move |_| match save_action.value().get() {
+        Some(Ok(saved)) => {
+            set_initial.set(items.get_untracked());
+            toaster.success(format!("Cart #{} saved.", saved.id));
+        }
+        Some(Err(err)) => toaster.error(format!("Saving failed: {err}")),
+        None => {}
+    });
+
+    let delete_action = Action::new_local(|cart_id: &u32| {
+        let cart_id = *cart_id;
+        async move { delete_a_cart(cart_id).await.map(|_| cart_id).map_err(|e| e.to_string()) }
+    });
+
+    Effect::new(move |_| match delete_action.value().get() {
+        Some(Ok(cart_id)) => {
+            set_confirm_open.set(false);
+            // Nothing left to save once the cart is gone
+            set_initial.set(items.get_untracked());
+            toaster.success(format!("Cart #{cart_id} deleted."));
+            navigate(&AppRoute::Carts.href(), Default::default());
+        }
+        Some(Err(err)) => {
+            set_confirm_open.set(false);
+            toaster.error(format!("Delete failed: {err}"));
+        }
+        None => {}
+    });
+
+    let save = move || {
+        if let Some(Ok(data)) = data.get_untracked() {
+            save_action.dispatch(Cart { products: items.get_untracked(), ..data.cart });
+        }
+    };
+
+    view! {
+        <section class="p-6 space-y-6">
+            <A href=AppRoute::Carts.href() attr:class="text-sm text-muted-foreground hover:text-foreground">"← Back to carts"</A>
+
+            <Transition fallback=|| view! { <CartDetailSkeleton /> }>
+                {move || Suspend::new(async move {
+                    Ok::<_, LoadError>(match data.await {
+                        Err(err_msg) => return Err(LoadError::new("this cart", err_msg)),
+                        Ok(CartDetailData { cart, products, user }) => {
+                            let cart_id = cart.id;
+                            let user_id = cart.user_id;
+                            let line_products = products.clone();
+                            view! {
+                                <div class="flex flex-wrap items-start justify-between gap-4">
+                                    <div>
+                                        <h1 class="text-2xl font-semibold">{format!("Cart #{cart_id}")}</h1>
+                                        <p class="text-sm text-muted-foreground">
+                                            {format_cart_date(&cart.date)} " · "
+                                            <A href=AppRoute::EditUser { id: user_id }.href() attr:class="hover:underline">
+                                                {user.map(|user| user.username).unwrap_or_else(|| format!("User #{user_id}"))}
+                                            </A>
+                                        </p>
+                                    </div>
+                                    <Button variant=ButtonVariant::Destructive on_click=move || set_confirm_open.set(true)>
+                                        "Delete cart"
+                                    </Button>
+                                </div>
+
+                                <div class="overflow-x-auto rounded-md border">
+                                    <table class="w-full text-sm">
+                                        <thead class="bg-muted/50 text-muted-foreground">
+                                            <tr>
+                                                <th scope="col" class="px-4 py-3 text-left font-medium">"Product"</th>
+                                                <th scope="col" class="px-4 py-3 text-right font-medium">"Unit price"</th>
+                                                <th scope="col" class="px-4 py-3 text-center font-medium">"Quantity"</th>
+                                                <th scope="col" class="px-4 py-3 text-right font-medium">"Subtotal"</th>
+                                                <th scope="col" class="px-4 py-3"><span class="sr-only">"Actions"</span></th>
+                                            </tr>
+                                        </thead>
+                                        <tbody>
+                                            <Show
+                                                when=move || !items.with(Vec::is_empty)
+                                                fallback=|| view! {
+                                                    <tr>
+                                                        <td colspan="5" class="px-4 py-12 text-center text-muted-foreground">"This cart is empty."</td>
+                                                    </tr>
+                                                }
+                                            >
+                                                <For
+                                                    each=move || items.get()
+                                                    key=|item| item.product_id
+                                                    children={
+                                                        let line_products = line_products.clone();
+                                                        move |item| {
+                                                            let product_id = item.product_id;
+                                                            let product = line_products.get(&product_id).cloned();
+                                                            let price = product.as_ref().map(|product| product.price).unwrap_or_default();
+                                                            let quantity = move || {
+                                                                items.with(|items| {
+                                                                    items.iter().find(|item| item.product_id == product_id).map(|item| item.quantity).unwrap_or_default()
+                                                                })
+                                                            };
+                                                            view! {
+                                                                <tr class="border-t">
+                                                                    <td class="px-4 py-3">
+                                                                        {match product {
+                                                                            Some(product) => view! {
+                                                                                <A href=AppRoute::ProductDetail { id: product_id }.href() attr:class="flex items-center gap-3 hover:underline">
+                                                                                    <img src=product.image alt="" class="size-10 rounded object-contain bg-white" />
+                                                                                    <span class="font-medium">{product.title}</span>
+                                                                                </A>
+                                                                            }.into_any(),
+                                                                            None => view! {
+                                                                                <span class="text-muted-foreground">{format!("Unknown product #{product_id}")}</span>
+                                                                            }.into_any(),
+                                                                        }}
+                                                                    </td>
+                                                                    <td class="px-4 py-3 text-right tabular-nums">{format_price(price)}</td>
+                                                                    <td class="px-4 py-3">
+                                                                        <div class="flex items-center justify-center gap-1">
+                                                                            <Button
+                                                                                variant=ButtonVariant::Outline
+                                                                                size=ButtonSize::Icon
+                                                                                attr:aria-label="Decrease quantity"
+                                                                                on_click=move || set_quantity(product_id, quantity().saturating_sub(1))
+                                                                            >
+                                                                                "−"
+                                                                            </Button>
+                                                                            <input
+                                                                                type="number"
+                                                                                min="1"
+                                                                                aria-label="Quantity"
+                                                                                class="w-16 rounded-md border bg-background px-2 py-1 text-center text-sm tabular-nums outline-none focus:ring-2 focus:ring-ring/50"
+                                                                                prop:value=move || quantity().to_string()
+                                                                                on:change=move |ev| {
+                                                                                    if let Ok(value) = event_target_value(&ev).trim().parse::<u32>() {
+                                                                                        set_quantity(product_id, value);
+                                                                                    }
+                                                                                }
+                                                                            />
+                                                                            <Button
+                                                                                variant=ButtonVariant::Outline
+                                                                                size=ButtonSize::Icon
+                                                                                attr:aria-label="Increase quantity"
+                                                                                on_click=move || set_quantity(product_id, quantity() + 1)
+                                                                            >
+                                                                                "+"
+                                                                            </Button>
+                                                                        </div>
+                                                                    </td>
+                                                                    <td class="px-4 py-3 text-right tabular-nums">{move || format_price(price * quantity() as f64)}</td>
+                                                                    <td class="px-4 py-3 text-right">
+                                                                        <Button
+                                                                            variant=ButtonVariant::Ghost
+                                                                            size=ButtonSize::Sm
+                                                                            class="text-destructive".to_string()
+                                                                            on_click=move || set_quantity(product_id, 0)
+                                                                        >
+                                                                            "Remove"
+                                                                        </Button>
+                                                                    </td>
+                                                                </tr>
+                                                            }
+                                                        }
+                                                    }
+                                                />
+                                            </Show>
+                                        </tbody>
+                                        <tfoot class="border-t bg-muted/30 font-medium">
+                                            <tr>
+                                                <td class="px-4 py-3" colspan="2">"Total"</td>
+                                                <td class="px-4 py-3 text-center tabular-nums">{move || items.with(|items| item_count(items))}</td>
+                                                <td class="px-4 py-3 text-right tabular-nums">
+                                                    {move || items.with(|items| format_price(cart_total(items, &products)))}
+                                                </td>
+                                                <td></td>
+                                            </tr>
+                                        </tfoot>
+                                    </table>
+                                </div>
+
+                                <div class="flex items-center gap-2">
+                                    <Button attr:disabled=move || !dirty.get() || save_action.pending().get() on_click=save>
+                                        {move || save_action.pending().get().then(|| view! {
+                                            <IconView icon=Icon::LOADER_CIRCLE.clone() class="animate-spin" />
+                                        })}
+                                        "Save changes"
+                                    </Button>
+                                    <Button
+                                        variant=ButtonVariant::Ghost
+                                        attr:disabled=move || !dirty.get()
+                                        on_click=move || set_items.set(initial.get_untracked())
+                                    >
+                                        "Reset"
+                                    </Button>
+                                    <span class="text-xs text-muted-foreground" class:hidden=move || !dirty.get()>
+                                        "Unsaved changes"
+                                    </span>
+                                </div>
+
+                                <ConfirmDialog
+                                    open=confirm_open
+                                    title="Delete cart?".to_string()
+                                    description=format!("Cart #{cart_id} will be removed permanently. This cannot be undone.")
+                                    pending=delete_action.pending()
+                                    on_confirm=move || {
+                                        delete_action.dispatch(cart_id);
+                                    }
+                                    on_cancel=move || set_confirm_open.set(false)
+                                />
+                            }.into_any()
+                        }
+                    })
+                })}
+            </Transition>
+        </section>
+    }
+}
@@ -0,0 +1,213 @@
+use crate::api::cart_api::{Cart, CartItem, delete_a_cart, get_all_carts};
+use crate::api::product_api::{Product, get_all_products};
+use crate::api::users_api::{User, get_all_users};
+use crate::components::base::button::{Button, ButtonSize, ButtonVariant};
+use crate::components::ui::{
+    confirm_dialog::ConfirmDialog,
+    pagination::Pagination,
+    route_error_boundary::LoadError,
+    skeleton::SkeletonTable,
+    toast::use_toaster,
+};
+use crate::routes::AppRoute;
+use crate::utils::currency::format_price;
+use leptos::prelude::*;
+use leptos_router::components::A;
+use std::collections::{HashMap, HashSet};
+
+const PAGE_SIZE: usize = 10;
+
+/// Sum of line quantities.
+pub fn item_count(items: &[CartItem]) -> u32 {
+    items.iter().map(|item| item.quantity).sum()
+}
+
+/// Cart total from catalogue prices, lines whose product is unknown count as zero.
+pub fn cart_total(items: &[CartItem], products: &HashMap<u32, Product>) -> f64 {
+    items
+        .iter()
+        .filter_map(|item| products.get(&item.product_id).map(|product| product.price * item.quantity as f64))
+        .sum()
+}
+
+/// `YYYY-MM-DD` part of a FakeStore timestamp.
+pub fn format_cart_date(date: &str) -> String {
+    date.get(..10).unwrap_or(date).to_string()
+}
+
+pub async fn load_products_by_id() -> Result<HashMap<u32, Product>, String> {
+    get_all_products()
+        .await
+        .map(|products| products.into_iter().map(|product| (product.id, product)).collect())
+        .map_err(|e| e.to_string())
+}
+
+#[derive(Clone, Debug)]
+struct CartsData {
+    carts: Vec<Cart>,
+    products: HashMap<u32, Product>,
+    users: HashMap<u32, User>,
+}
+
+async fn load_carts() -> Result<CartsData, String> {
+    let (carts, products, users) = futures::join!(get_all_carts(), load_products_by_id(), get_all_users());
+    Ok(CartsData {
+        carts: carts.map_err(|e| e.to_string())?,
+        products: products?,
+        users: users
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(|user| (user.id, user))
+            .collect(),
+    })
+}
+
+#[component]
+pub fn Carts() -> impl IntoView {
+    let toaster = use_toaster();
+    let data = LocalResource::new(load_carts);
+
+    let page = RwSignal::new(0usize);
+    // FakeStore does not persist deletes, so removed carts are hidden locally
+    let (removed, set_removed) = signal(HashSet::<u32>::new());
+    let (pending_delete, set_pending_delete) = signal(None::<u32>);
+
+    let delete_action = Action::new_local(|cart_id: &u32| {
+        let cart_id = *cart_id;
+        async move { delete_a_cart(cart_id).await.map(|_| cart_id).map_err(|e| e.to_string()) }
+    });
+
+    Effect::new(move |_| match delete_action.value().get() {
+        Some(Ok(cart_id)) => {
+            set_removed.update(|removed| {
+                removed.insert(cart_id);
+            });
+            set_pending_delete.set(None);
+            toaster.success(format!("Cart #{cart_id} deleted."));
+        }
+        Some(Err(err)) => {
+            set_pending_delete.set(None);
+            toaster.error(format!("Delete failed: {err}"));
+        }
+        None => {}
+    });
+
+    let visible_carts = Memo::new(move |_| {
+        let removed = removed.get();
+        let mut carts: Vec<Cart> = data
+            .get()
+            .and_then(Result::ok)
+            .map(|data| data.carts)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|cart| !removed.contains(&cart.id))
+            .collect();
+        // Newest first, ISO timestamps sort lexically
+        carts.sort_by(|a, b| b.date.cmp(&a.date));
+        carts
+    });
+    let total = Signal::derive(move || visible_carts.with(Vec::len));
+
+    view! {
+        <section class="p-6 space-y-4">
+            <div>
+                <h1 class="text-2xl font-semibold">"Carts"</h1>
+                <p class="text-sm text-muted-foreground">"Shopping carts placed by store users."</p>
+            </div>
+
+            <Transition fallback=|| view! { <SkeletonTable rows=10 columns=6 label="Loading carts..." /> }>
+                {move || Suspend::new(async move {
+                    Ok::<_, LoadError>(match data.await {
+                        Err(err_msg) => return Err(LoadError::new("carts", err_msg)),
+                        Ok(CartsData { products, users, .. }) => view! {
+                            <div class="overflow-x-auto rounded-md border">
+                                <table class="w-full text-sm">
+                                    <thead class="bg-muted/50 text-muted-foreground">
+                                        <tr>
+                                            <th scope="col" class="px-4 py-3 text-left font-medium">"Cart"</th>
+                                            <th scope="col" class="px-4 py-3 text-left font-medium">"User"</th>
+                                            <th scope="col" class="px-4 py-3 text-left font-medium">"Date"</th>
+                                            <th scope="col" class="px-4 py-3 text-right font-medium">"Items"</th>
+                                            <th scope="col" class="px-4 py-3 text-right font-medium">"Total"</th>
+                                            <th scope="col" class="px-4 py-3 text-right font-medium">"Actions"</th>
+                                        </tr>
+                                    </thead>
+                                    <tbody>
+                                        <Show
+                                            when=move || total.get() > 0
+                                            fallback=|| view! {
+                                                <tr>
+                                                    <td colspan="6" class="px-4 py-12 text-center text-muted-foreground">"No carts found."</td>
+                                                </tr>
+                                            }
+                                        >
+                                            {
+                                                let products = products.clone();
+                                                let users = users.clone();
+                                                move || {
+                                                    let start = page.get() * PAGE_SIZE;
+                                                    visible_carts.get().into_iter().skip(start).take(PAGE_SIZE).map(|cart| {
+                                                        let cart_id = cart.id;
+                                                        let user_name = users
+                                                            .get(&cart.user_id)
+                                                            .map(|user| user.username.clone())
+                                                            .unwrap_or_else(|| format!("User #{}", cart.user_id));
+                                                        view! {
+                                                            <tr class="border-t hover:bg-muted/30">
+                                                                <td class="px-4 py-3">
+                                                                    <A href=AppRoute::CartDetail { id: cart_id }.href() attr:class="font-medium hover:underline">{format!("#{cart_id}")}</A>
+                                                                </td>
+                                                                <td class="px-4 py-3">
+                                                                    <A href=AppRoute::EditUser { id: cart.user_id }.href() attr:class="hover:underline">{user_name}</A>
+                                                                </td>
+                                                                <td class="px-4 py-3 text-muted-foreground">{format_cart_date(&cart.date)}</td>
+                                                                <td class="px-4 py-3 text-right tabular-nums">{item_count(&cart.products)}</td>
+                                                                <td class="px-4 py-3 text-right tabular-nums">{format_price(cart_total(&cart.products, &products))}</td>
+                                                                <td class="px-4 py-3">
+                                                                    <div class="flex justify-end gap-1">
+                                                                        <A href=AppRoute::CartDetail { id: cart_id }.href() attr:class="inline-flex h-8 items-center rounded-md px-3 text-sm font-medium hover:bg-accent">
+                                                                            "View"
+                                                                        </A>
+                                                                        <Button
+                                                                            variant=ButtonVariant::Ghost
+                                                                            size=ButtonSize::Sm
+                                                                            class="text-destructive".to_string()
+                                                                            on_click=move || set_pending_delete.set(Some(cart_id))
+                                                                        >
+                                                                            "Delete"
+                                                                        </Button>
+                                                                    </div>
+                                                                </td>
+                                                            </tr>
+                                                        }
+                                                    }).collect_view()
+                                                }
+                                            }
+                                        </Show>
+                                    </tbody>
+                                </table>
+                            </div>
+                            <Pagination page=page total=total page_size=PAGE_SIZE />
+                        }.into_any(),
+                    })
+                })}
+            </Transition>
+
+            <ConfirmDialog
+                open=Signal::derive(move || pending_delete.get().is_some())
+                title="Delete cart?".to_string()
+                description=Signal::derive(move || {
+                    let cart_id = pending_delete.get().unwrap_or_default();
+                    format!("Cart #{cart_id} will be removed permanently. This cannot be undone.")
+                })
+                pending=delete_action.pending()
+                on_confirm=move || {
+                    if let Some(cart_id) = pending_delete.get_untracked() {
+                        delete_action.dispatch(cart_id);
+                    }
+                }
+                on_cancel=move || set_pending_delete.set(None)
+            />
+        </section>
+    }
+}
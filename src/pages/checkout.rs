@@ -0,0 +1,732 @@
+use crate::api::cart_api::{Cart, CartItem, GUEST_USER_ID, add_a_cart};
+use crate::api::product_api::{Product, get_all_products};
+use crate::api::users_api::{User, get_a_user};
+use crate::cart::guest::use_guest_cart;
+use crate::cart::payment::{CardDetails, PaymentReceipt, PaymentRequest, is_valid_card_number, use_payment_provider};
+use crate::components::base::button::{Button, ButtonVariant};
+use crate::components::base::icons::*;
+use crate::routes::AppRoute;
+use crate::session::context::use_session;
+use crate::utils::currency::{CURRENCY, format_price};
+use crate::utils::drafts::{discard_draft, load_draft, save_draft};
+use crate::cn;
+use leptos::{ev::SubmitEvent, prelude::*};
+use leptos_router::NavigateOptions;
+use leptos_router::components::A;
+use leptos_router::hooks::{use_navigate, use_query_map};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+const DRAFT_NAME: &str = "checkout";
+
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+enum Step {
+    Review,
+    Shipping,
+    Delivery,
+    Payment,
+    Confirmation,
+}
+
+impl Step {
+    const ALL: [Step; 5] = [Step::Review, Step::Shipping, Step::Delivery, Step::Payment, Step::Confirmation];
+
+    fn slug(&self) -> &'static str {
+        match self {
+            Step::Review => "review",
+            Step::Shipping => "shipping",
+            Step::Delivery => "delivery",
+            Step::Payment => "payment",
+            Step::Confirmation => "confirmation",
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Step::Review => "Cart",
+            Step::Shipping => "Address",
+            Step::Delivery => "Delivery",
+            Step::Payment => "Payment",
+            Step::Confirmation => "Done",
+        }
+    }
+
+    fn from_slug(slug: &str) -> Self {
+        Self::ALL.into_iter().find(|step| step.slug() == slug).unwrap_or(Step::Review)
+    }
+
+    fn previous(&self) -> Option<Step> {
+        Self::ALL.into_iter().rev().find(|step| step < self)
+    }
+
+    fn href(&self) -> String {
+        format!("{}?step={}", AppRoute::Checkout.href(), self.slug())
+    }
+}
+
+/// Mirrors the `name`, `address` and `phone` fields of a FakeStore user.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+struct ShippingAddress {
+    firstname: String,
+    lastname: String,
+    street: String,
+    number: String,
+    city: String,
+    zipcode: String,
+    phone: String,
+}
+
+impl From<User> for ShippingAddress {
+    fn from(user: User) -> Self {
+        let name = user.name.unwrap_or_default();
+        let address = user.address.unwrap_or_default();
+        Self {
+            firstname: name.firstname,
+            lastname: name.lastname,
+            street: address.street,
+            number: if address.number == 0 { String::new() } else { address.number.to_string() },
+            city: address.city,
+            zipcode: address.zipcode,
+            phone: user.phone.unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq)]
+struct AddressErrors {
+    firstname: Option<&'static str>,
+    lastname: Option<&'static str>,
+    street: Option<&'static str>,
+    number: Option<&'static str>,
+    city: Option<&'static str>,
+    zipcode: Option<&'static str>,
+    phone: Option<&'static str>,
+}
+
+impl AddressErrors {
+    fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+fn required(value: &str, message: &'static str) -> Option<&'static str> {
+    value.trim().is_empty().then_some(message)
+}
+
+fn validate_address(address: &ShippingAddress) -> AddressErrors {
+    AddressErrors {
+        firstname: required(&address.firstname, "First name is required."),
+        lastname: required(&address.lastname, "Last name is required."),
+        street: required(&address.street, "Street is required."),
+        number: required(&address.number, "House number is required.").or_else(|| {
+            address.number.trim().parse::<u32>().is_err().then_some("Use digits only.")
+        }),
+        city: required(&address.city, "City is required."),
+        zipcode: required(&address.zipcode, "ZIP code is required.").or_else(|| {
+            (!address.zipcode.trim().chars().all(|c| c.is_ascii_digit() || c == '-')).then_some("Enter a valid ZIP code.")
+        }),
+        phone: required(&address.phone, "Phone is required.").or_else(|| {
+            let digits = address.phone.chars().filter(char::is_ascii_digit).count();
+            (digits < 7 || address.phone.chars().any(|c| c.is_alphabetic())).then_some("Enter a valid phone number.")
+        }),
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+enum ShippingMethod {
+    #[default]
+    Standard,
+    Express,
+    NextDay,
+}
+
+impl ShippingMethod {
+    const ALL: [ShippingMethod; 3] = [ShippingMethod::Standard, ShippingMethod::Express, ShippingMethod::NextDay];
+
+    fn label(&self) -> &'static str {
+        match self {
+            ShippingMethod::Standard => "Standard",
+            ShippingMethod::Express => "Express",
+            ShippingMethod::NextDay => "Next day",
+        }
+    }
+
+    fn eta(&self) -> &'static str {
+        match self {
+            ShippingMethod::Standard => "5-7 business days",
+            ShippingMethod::Express => "2-3 business days",
+            ShippingMethod::NextDay => "Next business day",
+        }
+    }
+
+    fn cost(&self) -> f64 {
+        match self {
+            ShippingMethod::Standard => 0.0,
+            ShippingMethod::Express => 9.99,
+            ShippingMethod::NextDay => 19.99,
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq)]
+struct CardErrors {
+    holder: Option<&'static str>,
+    number: Option<&'static str>,
+    expiry: Option<&'static str>,
+    cvc: Option<&'static str>,
+}
+
+impl CardErrors {
+    fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+/// `MM/YY` that is not in the past.
+fn is_valid_expiry(expiry: &str) -> bool {
+    let Some((month, year)) = expiry.trim().split_once('/') else {
+        return false;
+    };
+    let (Ok(month), Ok(year)) = (month.trim().parse::<u32>(), year.trim().parse::<u32>()) else {
+        return false;
+    };
+    let now = js_sys::Date::new_0();
+    let (current_year, current_month) = (now.get_full_year() % 100, now.get_month() + 1);
+    (1..=12).contains(&month) && (year > current_year || (year == current_year && month >= current_month))
+}
+
+fn validate_card(card: &CardDetails) -> CardErrors {
+    CardErrors {
+        holder: required(&card.holder, "Name on card is required."),
+        number: required(&card.number, "Card number is required.")
+            .or_else(|| (!is_valid_card_number(&card.number)).then_some("Enter a valid card number.")),
+        expiry: required(&card.expiry, "Expiry date is required.")
+            .or_else(|| (!is_valid_expiry(&card.expiry)).then_some("Use MM/YY, and a date that has not passed.")),
+        cvc: required(&card.cvc, "Security code is required.").or_else(|| {
+            let cvc = card.cvc.trim();
+            (!(3..=4).contains(&cvc.len()) || !cvc.chars().all(|c| c.is_ascii_digit())).then_some("Use the 3 or 4 digits on the card.")
+        }),
+    }
+}
+
+/// Everything but the card, kept as a draft so a reload does not lose it.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+struct CheckoutDraft {
+    address: ShippingAddress,
+    method: Option<ShippingMethod>,
+}
+
+#[derive(Clone, Debug)]
+struct OrderInput {
+    user_id: u32,
+    items: Vec<CartItem>,
+    total: f64,
+    card: CardDetails,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Order {
+    cart_id: u32,
+    reference: String,
+    total: f64,
+    draft: CheckoutDraft,
+}
+
+fn items_subtotal(items: &[CartItem], products: &HashMap<u32, Product>) -> f64 {
+    items
+        .iter()
+        .filter_map(|item| products.get(&item.product_id).map(|product| product.price * item.quantity as f64))
+        .sum()
+}
+
+/// Multi-step checkout at `/checkout?step=`. Each step is its own history
+/// entry, so the browser back button moves to the previous step.
+#[component]
+pub fn Checkout() -> impl IntoView {
+    let query = use_query_map();
+    let navigate = use_navigate();
+    let cart = use_guest_cart();
+    let session = use_session();
+    let payment = use_payment_provider();
+
+    let step = Memo::new(move |_| Step::from_slug(&query.read().get("step").unwrap_or_default()));
+    let products = LocalResource::new(|| async {
+        get_all_products()
+            .await
+            .map(|products| products.into_iter().map(|product| (product.id, product)).collect::<HashMap<_, _>>())
+            .map_err(|e| e.to_string())
+    });
+
+    let (draft, set_draft) = signal(load_draft::<CheckoutDraft>(DRAFT_NAME).unwrap_or_default());
+    let (card, set_card) = signal(CardDetails::default());
+    let (order, set_order) = signal(None::<Order>);
+    // Errors only show for a step once the user tried to leave it
+    let (attempted, set_attempted) = signal(None::<Step>);
+
+    Effect::new(move |_| save_draft(DRAFT_NAME, &draft.get()));
+
+    // Signed-in customers start from the address on their account
+    let account = LocalResource::new(move || {
+        let user_id = session.token().get().and(session.user_id());
+        async move {
+            match user_id {
+                Some(user_id) => get_a_user(user_id).await.ok(),
+                None => None,
+            }
+        }
+    });
+    Effect::new(move |_| {
+        if let Some(Some(user)) = account.get()
+            && draft.with_untracked(|draft| draft.address == ShippingAddress::default())
+        {
+            set_draft.update(|draft| draft.address = ShippingAddress::from(user));
+        }
+    });
+
+    let items = Signal::derive(move || cart.items());
+    let subtotal = Signal::derive(move || {
+        products.with(|products| match products {
+            Some(Ok(products)) => items_subtotal(&items.get(), products),
+            _ => 0.0,
+        })
+    });
+    // Totals count unpriced lines as zero, so paying waits for the prices
+    let prices_loaded = move || products.with(|products| matches!(products, Some(Ok(_))));
+    let price_error = move || {
+        products.with(|products| match products {
+            None => Some("Prices are still loading, try again in a moment."),
+            Some(Err(_)) => Some("Prices could not be loaded. Reload the page to try again."),
+            Some(Ok(_)) => None,
+        })
+    };
+    let shipping_cost = Signal::derive(move || draft.with(|draft| draft.method.unwrap_or_default().cost()));
+    let total = Signal::derive(move || subtotal.get() + shipping_cost.get());
+
+    let address_errors = Memo::new(move |_| draft.with(|draft| validate_address(&draft.address)));
+    let card_errors = Memo::new(move |_| validate_card(&card.get()));
+
+    let is_reachable = move |target: Step| match target {
+        Step::Review => true,
+        Step::Shipping => !items.with(Vec::is_empty),
+        Step::Delivery => !items.with(Vec::is_empty) && address_errors.with(AddressErrors::is_empty),
+        Step::Payment => {
+            !items.with(Vec::is_empty)
+                && address_errors.with(AddressErrors::is_empty)
+                && draft.with(|draft| draft.method.is_some())
+                && prices_loaded()
+        }
+        Step::Confirmation => order.with(Option::is_some),
+    };
+
+    // Deep links and reloads land on the furthest step whose inputs are complete
+    {
+        let navigate = navigate.clone();
+        Effect::new(move |_| {
+            let current = step.get();
+            // Wait for the prices before judging the payment step
+            if products.with(Option::is_none) {
+                return;
+            }
+            if !is_reachable(current) {
+                let fallback = Step::ALL.into_iter().filter(|step| *step < current && is_reachable(*step)).last();
+                navigate(
+                    &fallback.unwrap_or(Step::Review).href(),
+                    NavigateOptions { replace: true, ..Default::default() },
+                );
+            }
+        });
+    }
+
+    let go_to = {
+        let navigate = navigate.clone();
+        move |target: Step| navigate(&target.href(), Default::default())
+    };
+
+    // Kept once the card is charged, so retrying after a failed save only
+    // saves the order again instead of charging twice
+    let (receipt, set_receipt) = signal(None::<PaymentReceipt>);
+
+    let place_order = Action::new_local(move |input: &OrderInput| {
+        let input = input.clone();
+        let payment = payment.clone();
+        let paid = receipt.get_untracked();
+        async move {
+            let receipt = match paid {
+                Some(receipt) => receipt,
+                None => {
+                    let receipt = payment
+                        .provider()
+                        .charge(PaymentRequest {
+                            amount: input.total,
+                            currency: CURRENCY.code,
+                            card: input.card,
+                        })
+                        .await
+                        .map_err(|e| e.to_string())?;
+                    set_receipt.try_set(Some(receipt.clone()));
+                    receipt
+                }
+            };
+            let cart = add_a_cart(Cart {
+                id: 0,
+                user_id: input.user_id,
+                date: String::from(js_sys::Date::new_0().to_iso_string()),
+                products: input.items,
+            })
+            .await
+            .map_err(|e| {
+                format!(
+                    "Your payment went through (reference {}) but the order was not saved: {e}. Placing the order again only saves it, the card is not charged twice.",
+                    receipt.reference
+                )
+            })?;
+            Ok::<_, String>((cart.id, receipt.reference))
+        }
+    });
+
+    {
+        let go_to = go_to.clone();
+        Effect::new(move |_| {
+            if let Some(Ok((cart_id, reference))) = place_order.value().get() {
+                set_order.set(Some(Order {
+                    cart_id,
+                    reference,
+                    total: total.get_untracked(),
+                    draft: draft.get_untracked(),
+                }));
+                // Move on before emptying the cart, which makes the payment step unreachable
+                go_to(Step::Confirmation);
+                set_receipt.set(None);
+                set_card.set(CardDetails::default());
+                cart.clear();
+                discard_draft(DRAFT_NAME);
+                set_draft.set(CheckoutDraft::default());
+            }
+        });
+    }
+
+    let advance = {
+        let go_to = go_to.clone();
+        move |from: Step| {
+            set_attempted.set(Some(from));
+            let ready = match from {
+                Step::Review => !items.with_untracked(Vec::is_empty),
+                Step::Shipping => address_errors.with_untracked(AddressErrors::is_empty),
+                Step::Delivery => {
+                    if draft.with_untracked(|draft| draft.method.is_none()) {
+                        set_draft.update(|draft| draft.method = Some(ShippingMethod::default()));
+                    }
+                    untrack(prices_loaded)
+                }
+                Step::Payment => {
+                    if card_errors.with_untracked(CardErrors::is_empty)
+                        && untrack(prices_loaded)
+                        && !place_order.pending().get_untracked()
+                    {
+                        place_order.dispatch(OrderInput {
+                            user_id: session.user_id().unwrap_or(GUEST_USER_ID),
+                            items: items.get_untracked(),
+                            total: total.get_untracked(),
+                            card: card.get_untracked(),
+                        });
+                    }
+                    return;
+                }
+                Step::Confirmation => false,
+            };
+            if ready {
+                set_attempted.set(None);
+                if let Some(next) = Step::ALL.into_iter().find(|step| *step > from) {
+                    go_to(next);
+                }
+            }
+        }
+    };
+
+    let show_error = move |current: Step, error: Option<&'static str>| (attempted.get() == Some(current)).then_some(error).flatten();
+
+    let text_field = move |label: &'static str,
+                           name: &'static str,
+                           autocomplete: &'static str,
+                           value: Signal<String>,
+                           on_input: Callback<String>,
+                           error: Signal<Option<&'static str>>| {
+        let error_id = format!("{name}-error");
+        view! {
+            <div>
+                <label for=name class="text-sm font-medium">{label}</label>
+                <input
+                    id=name
+                    name=name
+                    type="text"
+                    autocomplete=autocomplete
+                    aria-describedby=error_id.clone()
+                    aria-invalid=move || error.get().is_some().to_string()
+                    class="mt-1 w-full rounded-md border bg-background px-3 py-2 text-sm outline-none focus:ring-2 focus:ring-ring/50 aria-invalid:border-destructive"
+                    prop:value=move || value.get()
+                    on:input=move |ev| on_input.run(event_target_value(&ev))
+                />
+                <p id=error_id class="mt-1 text-xs text-destructive">{move || error.get()}</p>
+            </div>
+        }
+    };
+
+    let address_field = move |label: &'static str,
+                              name: &'static str,
+                              autocomplete: &'static str,
+                              get: fn(&ShippingAddress) -> String,
+                              set: fn(&mut ShippingAddress, String),
+                              error: fn(&AddressErrors) -> Option<&'static str>| {
+        text_field(
+            label,
+            name,
+            autocomplete,
+            Signal::derive(move || draft.with(|draft| get(&draft.address))),
+            Callback::new(move |value| set_draft.update(|draft| set(&mut draft.address, value))),
+            Signal::derive(move || show_error(Step::Shipping, error(&address_errors.get()))),
+        )
+    };
+
+    let card_field = move |label: &'static str,
+                           name: &'static str,
+                           autocomplete: &'static str,
+                           get: fn(&CardDetails) -> String,
+                           set: fn(&mut CardDetails, String),
+                           error: fn(&CardErrors) -> Option<&'static str>| {
+        text_field(
+            label,
+            name,
+            autocomplete,
+            Signal::derive(move || card.with(get)),
+            Callback::new(move |value| set_card.update(|card| set(card, value))),
+            Signal::derive(move || show_error(Step::Payment, error(&card_errors.get()))),
+        )
+    };
+
+    let summary = move || {
+        view! {
+            <dl class="space-y-2 text-sm">
+                <div class="flex justify-between"><dt class="text-muted-foreground">"Subtotal"</dt><dd class="tabular-nums">{move || format_price(subtotal.get())}</dd></div>
+                <div class="flex justify-between">
+                    <dt class="text-muted-foreground">"Shipping"</dt>
+                    <dd class="tabular-nums">
+                        {move || match shipping_cost.get() {
+                            cost if cost == 0.0 => "Free".to_string(),
+                            cost => format_price(cost),
+                        }}
+                    </dd>
+                </div>
+                <div class="flex justify-between border-t pt-2 font-semibold"><dt>"Total"</dt><dd class="tabular-nums">{move || format_price(total.get())}</dd></div>
+            </dl>
+        }
+    };
+
+    let step_actions = {
+        let go_to = go_to.clone();
+        let advance = advance.clone();
+        move |current: Step, next_label: &'static str| {
+            let go_to = go_to.clone();
+            let advance = advance.clone();
+            view! {
+                <div class="flex items-center justify-between gap-2 pt-4">
+                    {current.previous().map(|previous| {
+                        let go_to = go_to.clone();
+                        view! {
+                            <Button variant=ButtonVariant::Ghost attr:type="button" on_click=move || go_to(previous)>
+                                "← Back"
+                            </Button>
+                        }
+                    })}
+                    <Button
+                        class="ml-auto".to_string()
+                        attr:type="submit"
+                        attr:disabled=move || place_order.pending().get()
+                        on_click=move || advance(current)
+                    >
+                        {move || (current == Step::Payment && place_order.pending().get()).then(|| view! {
+                            <IconView icon=Icon::LOADER_CIRCLE.clone() class="animate-spin" />
+                        })}
+                        {next_label}
+                    </Button>
+                </div>
+            }
+        }
+    };
+
+    // Buttons carry the logic, the forms only need to stop the page reload
+    let prevent_submit = |ev: SubmitEvent| ev.prevent_default();
+
+    view! {
+        <section class="mx-auto max-w-5xl space-y-8 px-6 py-10">
+            <h1 class="text-2xl font-semibold">"Checkout"</h1>
+
+            <ol class="flex flex-wrap gap-2 text-sm" aria-label="Checkout progress">
+                {Step::ALL.into_iter().map(|item| view! {
+                    <li
+                        aria-current=move || (step.get() == item).then_some("step")
+                        class=move || cn!(
+                            "flex items-center gap-2 rounded-full border px-3 py-1",
+                            if step.get() == item { "border-primary bg-primary text-primary-foreground" } else { "" },
+                            if step.get() > item { "border-primary text-primary" } else { "" }
+                        )
+                    >
+                        {item.label()}
+                    </li>
+                }).collect_view()}
+            </ol>
+
+            {move || match step.get() {
+                Step::Review => view! {
+                    <form class="space-y-4" on:submit=prevent_submit>
+                        <h2 class="text-lg font-medium">"Review your cart"</h2>
+                        {move || match products.get() {
+                            None => view! {
+                                <div class="flex items-center gap-2 py-8 text-muted-foreground" role="status">
+                                    <IconView icon=Icon::LOADER_CIRCLE.clone() class="animate-spin" />
+                                    "Loading cart..."
+                                </div>
+                            }.into_any(),
+                            Some(Err(err_msg)) => view! {
+                                <p class="text-sm text-destructive" role="alert">"Could not load products: " {err_msg}</p>
+                            }.into_any(),
+                            Some(Ok(_)) if items.with(Vec::is_empty) => view! {
+                                <p class="text-muted-foreground">
+                                    "Your cart is empty. " <A href=AppRoute::Home.href() attr:class="underline">"Continue shopping"</A>
+                                </p>
+                            }.into_any(),
+                            Some(Ok(products)) => view! {
+                                <ul class="divide-y rounded-lg border">
+                                    {items.get().into_iter().map(|item| {
+                                        let product = products.get(&item.product_id).cloned();
+                                        let (title, price) = product
+                                            .as_ref()
+                                            .map(|product| (product.title.clone(), product.price))
+                                            .unwrap_or_else(|| (format!("Product #{}", item.product_id), 0.0));
+                                        view! {
+                                            <li class="flex items-center gap-4 p-4 text-sm">
+                                                {product.map(|product| view! {
+                                                    <img src=product.image alt="" class="size-12 shrink-0 rounded bg-white object-contain" />
+                                                })}
+                                                <span class="flex-1 font-medium">{title}</span>
+                                                <span class="text-muted-foreground tabular-nums">{format!("{} × {}", item.quantity, format_price(price))}</span>
+                                                <span class="w-24 text-right font-medium tabular-nums">{format_price(price * item.quantity as f64)}</span>
+                                            </li>
+                                        }
+                                    }).collect_view()}
+                                </ul>
+                                <div class="ml-auto max-w-xs">{summary()}</div>
+                                <p class="text-xs text-muted-foreground">"Change quantities from the cart button in the menu bar."</p>
+                                {step_actions(Step::Review, "Continue to address")}
+                            }.into_any(),
+                        }}
+                    </form>
+                }.into_any(),
+
+                Step::Shipping => view! {
+                    <form class="max-w-xl space-y-4" on:submit=prevent_submit novalidate>
+                        <h2 class="text-lg font-medium">"Shipping address"</h2>
+                        <div class="grid gap-4 sm:grid-cols-2">
+                            {address_field("First name", "firstname", "given-name", |a| a.firstname.clone(), |a, v| a.firstname = v, |e| e.firstname)}
+                            {address_field("Last name", "lastname", "family-name", |a| a.lastname.clone(), |a, v| a.lastname = v, |e| e.lastname)}
+                        </div>
+                        <div class="grid gap-4 sm:grid-cols-[1fr_8rem]">
+                            {address_field("Street", "street", "address-line1", |a| a.street.clone(), |a, v| a.street = v, |e| e.street)}
+                            {address_field("Number", "number", "address-line2", |a| a.number.clone(), |a, v| a.number = v, |e| e.number)}
+                        </div>
+                        <div class="grid gap-4 sm:grid-cols-2">
+                            {address_field("City", "city", "address-level2", |a| a.city.clone(), |a, v| a.city = v, |e| e.city)}
+                            {address_field("ZIP code", "zipcode", "postal-code", |a| a.zipcode.clone(), |a, v| a.zipcode = v, |e| e.zipcode)}
+                        </div>
+                        {address_field("Phone", "phone", "tel", |a| a.phone.clone(), |a, v| a.phone = v, |e| e.phone)}
+                        {step_actions(Step::Shipping, "Continue to delivery")}
+                    </form>
+                }.into_any(),
+
+                Step::Delivery => view! {
+                    <form class="max-w-xl space-y-4" on:submit=prevent_submit>
+                        <fieldset class="space-y-2">
+                            <legend class="mb-2 text-lg font-medium">"Delivery method"</legend>
+                            {ShippingMethod::ALL.into_iter().map(|method| view! {
+                                <label class="flex cursor-pointer items-center gap-3 rounded-lg border p-4 has-[:checked]:border-primary has-[:checked]:bg-primary/5">
+                                    <input
+                                        type="radio"
+                                        name="shipping-method"
+                                        prop:checked=move || draft.with(|draft| draft.method.unwrap_or_default() == method)
+                                        on:change=move |_| set_draft.update(|draft| draft.method = Some(method))
+                                    />
+                                    <span class="flex-1">
+                                        <span class="block font-medium">{method.label()}</span>
+                                        <span class="block text-sm text-muted-foreground">{method.eta()}</span>
+                                    </span>
+                                    <span class="tabular-nums">
+                                        {if method.cost() == 0.0 { "Free".to_string() } else { format_price(method.cost()) }}
+                                    </span>
+                                </label>
+                            }).collect_view()}
+                        </fieldset>
+                        <div class="max-w-xs">{summary()}</div>
+                        {move || show_error(Step::Delivery, price_error()).map(|error| view! {
+                            <p class="text-sm text-destructive" role="alert">{error}</p>
+                        })}
+                        {step_actions(Step::Delivery, "Continue to payment")}
+                    </form>
+                }.into_any(),
+
+                Step::Payment => view! {
+                    <form class="max-w-xl space-y-4" on:submit=prevent_submit novalidate>
+                        <h2 class="text-lg font-medium">"Payment"</h2>
+                        <p class="text-sm text-muted-foreground">
+                            {format!("Processed by {}. Nothing is charged in this demo.", payment.provider().name())}
+                        </p>
+                        {card_field("Name on card", "cc-name", "cc-name", |c| c.holder.clone(), |c, v| c.holder = v, |e| e.holder)}
+                        {card_field("Card number", "cc-number", "cc-number", |c| c.number.clone(), |c, v| c.number = v, |e| e.number)}
+                        <div class="grid gap-4 sm:grid-cols-2">
+                            {card_field("Expiry (MM/YY)", "cc-exp", "cc-exp", |c| c.expiry.clone(), |c, v| c.expiry = v, |e| e.expiry)}
+                            {card_field("Security code", "cc-csc", "cc-csc", |c| c.cvc.clone(), |c, v| c.cvc = v, |e| e.cvc)}
+                        </div>
+                        <div class="max-w-xs">{summary()}</div>
+                        {move || match place_order.value().get() {
+                            Some(Err(err)) => Some(view! { <p class="text-sm text-destructive" role="alert">{err}</p> }),
+                            _ => None,
+                        }}
+                        {step_actions(Step::Payment, "Place order")}
+                    </form>
+                }.into_any(),
+
+                Step::Confirmation => match order.get() {
+                    Some(order) => view! {
+                        <div class="max-w-xl space-y-4 rounded-lg border p-6" role="status">
+                            <IconView icon=Icon::PACKAGE_CHECK.clone() class="size-10 text-green-600" />
+                            <h2 class="text-xl font-semibold">"Thank you for your order!"</h2>
+                            <p class="text-muted-foreground">
+                                {format!(
+                                    "Order #{} for {} will ship to {} {}, {} {}, {} {}.",
+                                    order.cart_id,
+                                    format_price(order.total),
+                                    order.draft.address.firstname,
+                                    order.draft.address.lastname,
+                                    order.draft.address.street,
+                                    order.draft.address.number,
+                                    order.draft.address.zipcode,
+                                    order.draft.address.city,
+                                )}
+                            </p>
+                            <p class="text-sm text-muted-foreground">
+                                {format!(
+                                    "{} delivery, {}. Payment reference {}.",
+                                    order.draft.method.unwrap_or_default().label(),
+                                    order.draft.method.unwrap_or_default().eta().to_lowercase(),
+                                    order.reference,
+                                )}
+                            </p>
+                            <A href=AppRoute::Home.href() attr:class="inline-flex rounded-md bg-primary px-4 py-2 text-sm text-primary-foreground">
+                                "Continue shopping"
+                            </A>
+                        </div>
+                    }.into_any(),
+                    None => ().into_any(),
+                },
+            }}
+        </section>
+    }
+}
@@ -0,0 +1,187 @@
+use crate::api::contact_api::{ContactMessage, send_contact_message};
+use crate::components::base::button::{Button, ButtonVariant};
+use crate::components::base::icons::*;
+use crate::routes::AppRoute;
+use crate::utils::validation::is_valid_email;
+use leptos::{ev::SubmitEvent, prelude::*};
+use leptos_router::components::A;
+
+const MIN_MESSAGE_LENGTH: usize = 20;
+const MAX_MESSAGE_LENGTH: usize = 2000;
+
+#[derive(Default, Debug, Clone, PartialEq)]
+struct FieldErrors {
+    name: Option<&'static str>,
+    email: Option<&'static str>,
+    subject: Option<&'static str>,
+    message: Option<String>,
+}
+
+impl FieldErrors {
+    fn is_empty(&self) -> bool {
+        self.name.is_none() && self.email.is_none() && self.subject.is_none() && self.message.is_none()
+    }
+}
+
+fn validate(data: &ContactMessage) -> FieldErrors {
+    let name = data.name.trim().is_empty().then_some("Name is required.");
+
+    let email = if data.email.trim().is_empty() {
+        Some("Email is required.")
+    } else if !is_valid_email(data.email.trim()) {
+        Some("Enter a valid email address.")
+    } else {
+        None
+    };
+
+    let subject = data.subject.trim().is_empty().then_some("Subject is required.");
+
+    let length = data.message.trim().chars().count();
+    let message = if length == 0 {
+        Some("Message is required.".to_string())
+    } else if length < MIN_MESSAGE_LENGTH {
+        Some(format!("Message must be at least {MIN_MESSAGE_LENGTH} characters."))
+    } else if length > MAX_MESSAGE_LENGTH {
+        Some(format!("Message must be at most {MAX_MESSAGE_LENGTH} characters."))
+    } else {
+        None
+    };
+
+    FieldErrors { name, email, subject, message }
+}
+
+/// Support form at `/contact`, posts to `CONTACT_URL`.
+#[component]
+pub fn Contact() -> impl IntoView {
+    let (form_data, set_form_data) = signal(ContactMessage::default());
+    let (submitted, set_submitted) = signal(false);
+
+    let field_errors = Memo::new(move |_| validate(&form_data.get()));
+
+    let send_action = Action::new_local(|message: &ContactMessage| {
+        let message = ContactMessage {
+            name: message.name.trim().to_string(),
+            email: message.email.trim().to_string(),
+            subject: message.subject.trim().to_string(),
+            message: message.message.trim().to_string(),
+        };
+        async move { send_contact_message(message).await.map_err(|e| e.to_string()) }
+    });
+    let sent = move || matches!(send_action.value().get(), Some(Ok(())));
+
+    let handle_form_submit = move |ev: SubmitEvent| {
+        ev.prevent_default();
+        set_submitted.set(true);
+        if field_errors.get_untracked().is_empty() && !send_action.pending().get_untracked() {
+            send_action.dispatch(form_data.get_untracked());
+        }
+    };
+
+    let start_over = move || {
+        set_form_data.set(ContactMessage::default());
+        set_submitted.set(false);
+        send_action.value().set(None);
+    };
+
+    let input_class = "mt-1 w-full rounded-md border bg-background px-3 py-2 text-sm outline-none focus:ring-2 focus:ring-ring/50 aria-invalid:border-destructive";
+
+    let field = move |label: &'static str,
+                      name: &'static str,
+                      input_type: &'static str,
+                      autocomplete: &'static str,
+                      value: fn(&ContactMessage) -> String,
+                      set: fn(&mut ContactMessage, String),
+                      error: fn(&FieldErrors) -> Option<&'static str>| {
+        let error_id = format!("{name}-error");
+        let show_error = move || submitted.get().then(|| error(&field_errors.get())).flatten();
+        view! {
+            <div>
+                <label for=name class="text-sm font-medium">{label}</label>
+                <input
+                    id=name
+                    name=name
+                    type=input_type
+                    autocomplete=autocomplete
+                    aria-describedby=error_id.clone()
+                    aria-invalid=move || show_error().is_some().to_string()
+                    class=input_class
+                    prop:value=move || value(&form_data.get())
+                    on:input=move |ev| set_form_data.update(|data| set(data, event_target_value(&ev)))
+                />
+                <p id=error_id class="mt-1 text-xs text-destructive">{show_error}</p>
+            </div>
+        }
+    };
+
+    let show_message_error = move || submitted.get().then(|| field_errors.get().message).flatten();
+
+    view! {
+        <section class="mx-auto max-w-xl space-y-6 px-6 py-12">
+            <div>
+                <h1 class="text-3xl font-semibold">"Contact support"</h1>
+                <p class="mt-2 text-muted-foreground">
+                    "Questions about an order, a product or your account? Send us a message and we'll reply by email."
+                </p>
+            </div>
+
+            <Show
+                when=sent
+                fallback=move || view! {
+                    <form class="space-y-4" on:submit=handle_form_submit novalidate>
+                        <div class="grid gap-4 sm:grid-cols-2">
+                            {field("Name", "name", "text", "name", |d| d.name.clone(), |d, v| d.name = v, |e| e.name)}
+                            {field("Email", "email", "email", "email", |d| d.email.clone(), |d, v| d.email = v, |e| e.email)}
+                        </div>
+                        {field("Subject", "subject", "text", "off", |d| d.subject.clone(), |d, v| d.subject = v, |e| e.subject)}
+                        <div>
+                            <label for="message" class="text-sm font-medium">"Message"</label>
+                            <textarea
+                                id="message"
+                                name="message"
+                                rows="6"
+                                maxlength=MAX_MESSAGE_LENGTH
+                                aria-describedby="message-error"
+                                aria-invalid=move || show_message_error().is_some().to_string()
+                                class=input_class
+                                prop:value=move || form_data.get().message
+                                on:input=move |ev| set_form_data.update(|data| data.message = event_target_value(&ev))
+                            ></textarea>
+                            <p id="message-error" class="mt-1 text-xs text-destructive">{show_message_error}</p>
+                        </div>
+
+                        {move || match send_action.value().get() {
+                            Some(Err(err)) => Some(view! {
+                                <div class="rounded-md border border-destructive/40 bg-destructive/5 p-4 text-sm" role="alert">
+                                    <p class="font-medium text-destructive">"Your message could not be sent."</p>
+                                    <p class="text-muted-foreground">{err}</p>
+                                    <p class="text-muted-foreground">"Check your connection and try again."</p>
+                                </div>
+                            }),
+                            _ => None,
+                        }}
+
+                        <Button attr:type="submit" attr:disabled=move || send_action.pending().get()>
+                            {move || send_action.pending().get().then(|| view! {
+                                <IconView icon=Icon::LOADER_CIRCLE.clone() class="animate-spin" />
+                            })}
+                            {move || if send_action.pending().get() { "Sending..." } else { "Send message" }}
+                        </Button>
+                    </form>
+                }
+            >
+                <div class="rounded-lg border bg-card p-6 text-center shadow-xs" role="status">
+                    <h2 class="text-xl font-semibold">"Thanks, your message is on its way."</h2>
+                    <p class="mt-2 text-sm text-muted-foreground">
+                        {move || format!("We'll get back to you at {} within two business days.", form_data.get().email.trim())}
+                    </p>
+                    <div class="mt-6 flex justify-center gap-2">
+                        <Button variant=ButtonVariant::Outline on_click=start_over>"Send another message"</Button>
+                        <A href=AppRoute::Home.href() attr:class="inline-flex h-9 items-center rounded-md bg-primary px-4 text-sm font-medium text-primary-foreground hover:bg-primary/90">
+                            "Continue shopping"
+                        </A>
+                    </div>
+                </div>
+            </Show>
+        </section>
+    }
+}
@@ -1,8 +1,166 @@
+use crate::api::cart_api::{Cart, get_all_carts};
+use crate::api::product_api::{Product, get_all_products};
+use crate::api::users_api::{User, get_all_users};
+use crate::components::base::icons::*;
+use crate::components::ui::{
+    charts::{BarChart, ChartPoint, ColumnChart},
+    route_error_boundary::LoadError,
+    skeleton::Skeleton,
+};
+use crate::pages::carts::{cart_total, format_cart_date};
+use crate::utils::currency::format_price;
 use leptos::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+
+const TOP_CATEGORIES: usize = 5;
+
+#[derive(Clone, Debug)]
+struct Overview {
+    products: Vec<Product>,
+    users: Vec<User>,
+    carts: Vec<Cart>,
+}
+
+impl Overview {
+    fn products_by_id(&self) -> HashMap<u32, Product> {
+        self.products.iter().map(|product| (product.id, product.clone())).collect()
+    }
+
+    fn revenue(&self) -> f64 {
+        let products = self.products_by_id();
+        self.carts.iter().map(|cart| cart_total(&cart.products, &products)).sum()
+    }
+
+    fn average_cart_value(&self) -> f64 {
+        if self.carts.is_empty() {
+            0.0
+        } else {
+            self.revenue() / self.carts.len() as f64
+        }
+    }
+
+    /// Categories ranked by revenue across all carts.
+    fn top_categories(&self) -> Vec<ChartPoint> {
+        let products = self.products_by_id();
+        let mut revenue = HashMap::<&str, f64>::new();
+        for item in self.carts.iter().flat_map(|cart| &cart.products) {
+            if let Some(product) = products.get(&item.product_id) {
+                *revenue.entry(product.category.as_str()).or_default() += product.price * item.quantity as f64;
+            }
+        }
+        let mut points: Vec<ChartPoint> = revenue
+            .into_iter()
+            .map(|(label, value)| ChartPoint { label: label.to_string(), value })
+            .collect();
+        points.sort_by(|a, b| b.value.total_cmp(&a.value));
+        points.truncate(TOP_CATEGORIES);
+        points
+    }
+
+    /// Number of carts per day, oldest first.
+    fn carts_over_time(&self) -> Vec<ChartPoint> {
+        let mut per_day = BTreeMap::<String, u32>::new();
+        for cart in &self.carts {
+            *per_day.entry(format_cart_date(&cart.date)).or_default() += 1;
+        }
+        per_day
+            .into_iter()
+            .map(|(day, count)| ChartPoint {
+                // MM-DD keeps the axis labels short
+                label: day.get(5..).unwrap_or(&day).to_string(),
+                value: count as f64,
+            })
+            .collect()
+    }
+}
+
+async fn load_overview() -> Result<Overview, String> {
+    let (products, users, carts) = futures::join!(get_all_products(), get_all_users(), get_all_carts());
+    Ok(Overview {
+        products: products.map_err(|e| e.to_string())?,
+        users: users.map_err(|e| e.to_string())?,
+        carts: carts.map_err(|e| e.to_string())?,
+    })
+}
+
+#[component]
+fn KpiCard(label: &'static str, value: String, icon: Icon) -> impl IntoView {
+    view! {
+        <div class="rounded-lg border bg-card p-4 shadow-xs">
+            <div class="flex items-center justify-between text-sm text-muted-foreground">
+                <span>{label}</span>
+                <IconView icon=icon />
+            </div>
+            <p class="mt-2 text-2xl font-semibold tabular-nums">{value}</p>
+        </div>
+    }
+}
+
+/// Placeholder with the footprint of the KPI row and the two chart panels.
+#[component]
+fn DashboardSkeleton() -> impl IntoView {
+    view! {
+        <div class="space-y-4" role="status">
+            <span class="sr-only">"Loading overview..."</span>
+            <div class="grid gap-4 sm:grid-cols-2 lg:grid-cols-5">
+                {(0..5).map(|_| view! { <Skeleton class="h-[5.5rem] rounded-lg" /> }).collect_view()}
+            </div>
+            <div class="grid gap-4 lg:grid-cols-2">
+                <Skeleton class="h-72 rounded-lg" />
+                <Skeleton class="h-72 rounded-lg" />
+            </div>
+        </div>
+    }
+}
 
 #[component]
 pub fn Dashboard() -> impl IntoView {
+    let overview = LocalResource::new(load_overview);
+
     view! {
-        "Dashboard"
+        <section class="p-6 space-y-6">
+            <div>
+                <h1 class="text-2xl font-semibold">"Dashboard"</h1>
+                <p class="text-sm text-muted-foreground">"An overview of the store catalogue, users and carts."</p>
+            </div>
+
+            <Transition fallback=|| view! { <DashboardSkeleton /> }>
+                {move || Suspend::new(async move {
+                    Ok::<_, LoadError>(match overview.await {
+                        Err(err_msg) => return Err(LoadError::new("the overview", err_msg)),
+                        Ok(overview) => {
+                            let top_categories = overview.top_categories();
+                            let carts_over_time = overview.carts_over_time();
+                            view! {
+                                <div class="grid gap-4 sm:grid-cols-2 lg:grid-cols-5">
+                                    <KpiCard label="Products" value=overview.products.len().to_string() icon=Icon::PRODUCTS.clone() />
+                                    <KpiCard label="Users" value=overview.users.len().to_string() icon=Icon::USER.clone() />
+                                    <KpiCard label="Carts" value=overview.carts.len().to_string() icon=Icon::CART.clone() />
+                                    <KpiCard label="Revenue" value=format_price(overview.revenue()) icon=Icon::DOLLAR_SIGN.clone() />
+                                    <KpiCard label="Average cart" value=format_price(overview.average_cart_value()) icon=Icon::RECEIPT.clone() />
+                                </div>
+
+                                <div class="grid gap-4 lg:grid-cols-2">
+                                    <div class="rounded-lg border bg-card p-4 shadow-xs">
+                                        <h2 class="font-medium">"Top categories"</h2>
+                                        <p class="mb-4 text-sm text-muted-foreground">"By revenue across carts"</p>
+                                        {if top_categories.is_empty() {
+                                            view! { <p class="text-sm text-muted-foreground">"No sales yet."</p> }.into_any()
+                                        } else {
+                                            view! { <BarChart points=top_categories format=format_price /> }.into_any()
+                                        }}
+                                    </div>
+                                    <div class="rounded-lg border bg-card p-4 shadow-xs">
+                                        <h2 class="font-medium">"Carts over time"</h2>
+                                        <p class="mb-4 text-sm text-muted-foreground">"Carts created per day"</p>
+                                        <ColumnChart points=carts_over_time label="Carts created per day" />
+                                    </div>
+                                </div>
+                            }.into_any()
+                        }
+                    })
+                })}
+            </Transition>
+        </section>
     }
 }
@@ -0,0 +1,24 @@
+use leptos::prelude::*;
+use leptos_router::components::A;
+use crate::components::ui::status_page::StatusPage;
+use crate::routes::AppRoute;
+
+/// Shown in place of a page the signed-in user lacks the permission for.
+#[component]
+pub fn Forbidden() -> impl IntoView {
+    view! {
+        <StatusPage
+            code="403"
+            title="Access Denied"
+            message="You don't have permission to view this page. Ask an administrator if you think you should."
+            class="min-h-[70vh]"
+        >
+            <A href=AppRoute::Dashboard.href() attr:class="bg-gray-800 hover:bg-black px-7 py-2.5 text-white rounded-md active:scale-95 transition-all">
+                "Go to dashboard"
+            </A>
+            <A href=AppRoute::Home.href() attr:class="border border-gray-300 px-7 py-2.5 text-gray-800 rounded-md active:scale-95 transition-all">
+                "Return Home"
+            </A>
+        </StatusPage>
+    }
+}
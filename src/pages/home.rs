@@ -1,60 +1,123 @@
-use crate::api::auth_api::{LoginRequest, try_login};
-use crate::components::base::button::{Button, ButtonVariant};
+use crate::api::product_api::{Product, get_all_categories, get_all_products};
+use crate::components::base::button::ButtonSize;
+use crate::components::base::icons::*;
+use crate::components::ui::{
+    add_to_cart_button::AddToCartButton,
+    product_card::ProductCard,
+    route_error_boundary::LoadError,
+    skeleton::SkeletonGrid,
+};
+use crate::cn;
+use crate::routes::AppRoute;
 use leptos::prelude::*;
+use leptos_router::components::A;
+use leptos_router::hooks::use_query_map;
 
+fn category_href(category: Option<&str>) -> String {
+    match category {
+        Some(category) => format!("{}?category={}", AppRoute::Home.href(), String::from(js_sys::encode_uri_component(category))),
+        None => AppRoute::Home.href(),
+    }
+}
+
+/// Public storefront: hero, category navigation and the product grid.
 #[component]
 pub fn Home() -> impl IntoView {
-    let login_action = Action::new_local(|input: &LoginRequest| {
-        let input = input.clone();
-        async move { try_login(input).await.map_err(|e| e.to_string()) }
+    let query = use_query_map();
+    let active_category = Memo::new(move |_| query.read().get("category").filter(|category| !category.is_empty()));
+
+    let products = LocalResource::new(|| async { get_all_products().await.map_err(|e| e.to_string()) });
+    // Category links are a nicety, the grid still works without them
+    let categories = LocalResource::new(|| async { get_all_categories().await.unwrap_or_default() });
+
+    let visible_products = Memo::new(move |_| {
+        let category = active_category.get();
+        products
+            .get()
+            .and_then(Result::ok)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|product| category.as_ref().is_none_or(|category| &product.category == category))
+            .collect::<Vec<Product>>()
     });
 
-    let login_result = login_action.value();
+    let category_link = move |label: String, category: Option<String>| {
+        let href = category_href(category.as_deref());
+        let is_active = Signal::derive(move || active_category.get() == category);
+        view! {
+            <A
+                href=href
+                attr:aria-current=move || is_active.get().then_some("page")
+                attr:class=move || cn!(
+                    "rounded-full border px-4 py-1.5 text-sm capitalize transition hover:bg-accent",
+                    if is_active.get() { "border-primary bg-primary text-primary-foreground hover:bg-primary/90" } else { "" }
+                )
+            >
+                {label}
+            </A>
+        }
+    };
 
     view! {
-        <main>
-            <div class="bg-gradient-to-tl from-blue-800 to-blue-500 font-mono flex flex-col min-h-screen p-8">
-                <div class="flex flex-col items-center justify-center m-auto gap-4">
-
-                    <h1 class="text-2xl font-bold italic mb-4">"Authentication"</h1>
-
-                    <Button
-                        on:click=move |_| {
-                            login_action.dispatch(LoginRequest {
-                                username: "johnd".to_string(),
-                                password: "m38rmF$".to_string(),
-                            });
-                        }
-                        variant=ButtonVariant::Outline
-                        attr:disabled=move || login_action.pending().get()
-                    >
-                        {move || if login_action.pending().get() { "Authenticating..." } else { "Login" }}
-                    </Button>
-
-                    <div class="mt-4 p-6 bg-blue-900/50 backdrop-blur-sm rounded-xl min-w-[350px] border border-blue-400/30 shadow-2xl">
-                        <h3 class="text-sm font-semibold uppercase tracking-wider text-blue-300 mb-3 border-b border-blue-400/20 pb-2">
-                            "Server Response"
-                        </h3>
-
-                        {move || match login_result.get() {
-                            None => view! {
-                                <p class="text-blue-200/50 italic text-sm">"Waiting for login..."</p>
-                            }.into_any(),
+        <div>
+            <section class="bg-gradient-to-tl from-blue-800 to-blue-500 text-white">
+                <div class="mx-auto flex max-w-6xl flex-col items-start gap-4 px-6 py-20">
+                    <p class="text-sm uppercase tracking-widest text-blue-100">"New season"</p>
+                    <h1 class="max-w-2xl text-4xl font-bold md:text-5xl">"Everyday essentials, delivered to your door."</h1>
+                    <p class="max-w-xl text-blue-100">"Clothing, jewellery and electronics picked for quality and fair prices."</p>
+                    <a href="#catalogue" class="mt-2 inline-flex items-center gap-2 rounded-full bg-white px-6 py-2.5 text-sm font-medium text-blue-800 transition hover:bg-blue-50">
+                        "Shop now"
+                        <IconView icon=Icon::ARROW_RIGHT.clone() />
+                    </a>
+                </div>
+            </section>
 
-                            Some(Ok(resp)) => view! {
-                                <div class="space-y-2 text-green-400">
-                                    <p class="font-medium">"✓ Success"</p>
-                                    <code class="text-xs break-all bg-black/20 p-2 block rounded">{resp.token}</code>
-                                </div>
-                            }.into_any(),
+            <section id="catalogue" class="mx-auto max-w-6xl space-y-6 px-6 py-12">
+                <div class="flex flex-wrap items-end justify-between gap-4">
+                    <h2 class="text-2xl font-semibold capitalize">
+                        {move || active_category.get().unwrap_or_else(|| "All products".to_string())}
+                    </h2>
+                </div>
 
-                            Some(Err(err_msg)) => view! {
-                                <p class="text-red-400 text-sm italic">"Error: " {err_msg}</p>
+                <nav aria-label="Categories" class="flex flex-wrap gap-2">
+                    {category_link("All".to_string(), None)}
+                    {move || categories.get().unwrap_or_default().into_iter().map(|category| {
+                        category_link(category.clone(), Some(category))
+                    }).collect_view()}
+                </nav>
+
+                <Transition fallback=|| view! { <SkeletonGrid count=8 label="Loading products..." /> }>
+                    {move || Suspend::new(async move {
+                        Ok::<_, LoadError>(match products.await {
+                            Err(err_msg) => return Err(LoadError::new("products", err_msg)),
+                            Ok(_) => view! {
+                                <Show
+                                    when=move || !visible_products.with(Vec::is_empty)
+                                    fallback=|| view! {
+                                        <p class="py-12 text-center text-muted-foreground">"No products in this category yet."</p>
+                                    }
+                                >
+                                    <div class="grid gap-6 sm:grid-cols-2 lg:grid-cols-3 xl:grid-cols-4">
+                                        <For
+                                            each=move || visible_products.get()
+                                            key=|product| product.id
+                                            children=|product| {
+                                                let product_id = product.id;
+                                                let title = product.title.clone();
+                                                view! {
+                                                    <ProductCard product=product href=AppRoute::ShopProduct { id: product_id }.href()>
+                                                        <AddToCartButton product_id=product_id title=title.clone() size=ButtonSize::Sm />
+                                                    </ProductCard>
+                                                }
+                                            }
+                                        />
+                                    </div>
+                                </Show>
                             }.into_any(),
-                        }}
-                    </div>
-                </div>
-            </div>
-        </main>
+                        })
+                    })}
+                </Transition>
+            </section>
+        </div>
     }
 }
@@ -1,30 +1,121 @@
 use crate::api::auth_api::{LoginRequest, try_login};
+use crate::cart::guest::use_guest_cart;
 use crate::components::base::icons::*;
+use crate::components::ui::toast::use_toaster;
+use gloo_storage::{LocalStorage, Storage};
 use leptos::{
-    ev::{Event, SubmitEvent},
+    ev::{Event, KeyboardEvent, SubmitEvent},
     prelude::*,
-    server::codee::string::FromToStringCodec,
     task::spawn_local,
     web_sys,
 };
 use leptos_router::components::A;
 use leptos_router::hooks::use_navigate;
-use leptos_use::use_cookie;
+use crate::routes::AppRoute;
+use crate::session::context::use_session;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+// Failed attempts allowed before the form locks
+const MAX_FAILED_ATTEMPTS: u32 = 5;
+// Lock duration, in milliseconds
+const COOL_DOWN: f64 = 30_000.0;
+// Persisted so a reload does not reset the lock
+const THROTTLE_KEY: &str = "login_throttle";
 
 #[derive(Default, Serialize, Deserialize, Debug, Clone)]
 struct FormData {
     username: String,
     password: String,
+    remember: bool,
+}
+
+#[derive(Default, Debug, Clone, PartialEq)]
+struct FieldErrors {
+    username: Option<&'static str>,
+    password: Option<&'static str>,
+}
+
+impl FieldErrors {
+    fn is_empty(&self) -> bool {
+        self.username.is_none() && self.password.is_none()
+    }
+}
+
+fn validate(data: &FormData) -> FieldErrors {
+    let username = if data.username.trim().is_empty() {
+        Some("Username is required.")
+    } else if data.username.contains(char::is_whitespace) {
+        Some("Username cannot contain spaces.")
+    } else {
+        None
+    };
+
+    // No length rule here: older accounts may have shorter passwords and the
+    // backend is the one that decides
+    let password = data.password.is_empty().then_some("Password is required.");
+
+    FieldErrors { username, password }
+}
+
+#[derive(Default, Serialize, Deserialize, Debug, Clone, Copy)]
+struct Throttle {
+    failures: u32,
+    locked_until: f64,
+}
+
+impl Throttle {
+    fn load() -> Self {
+        LocalStorage::get(THROTTLE_KEY).unwrap_or_default()
+    }
+
+    fn save(&self) {
+        let _ = LocalStorage::set(THROTTLE_KEY, self);
+    }
+
+    fn record_failure(mut self, now: f64) -> Self {
+        self.failures += 1;
+        if self.failures >= MAX_FAILED_ATTEMPTS {
+            self.failures = 0;
+            self.locked_until = now + COOL_DOWN;
+        }
+        self
+    }
+
+    fn remaining_secs(&self, now: f64) -> u32 {
+        ((self.locked_until - now).max(0.0) / 1000.0).ceil() as u32
+    }
 }
 
 #[component]
 pub fn Login() -> impl IntoView {
     let navigate = use_navigate();
-    let (_, set_cookie) = use_cookie::<String, FromToStringCodec>("auth_token");
+    let session = use_session();
+    let guest_cart = use_guest_cart();
+    let toaster = use_toaster();
     let (form_data, set_form_data) = signal(FormData::default());
-    let (is_submitting, set_is_submitting) = signal(false);
     let (err, set_err) = signal(String::new());
+    let (touched, set_touched) = signal((false, false));
+    let (show_password, set_show_password) = signal(false);
+    let (caps_lock, set_caps_lock) = signal(false);
+    let (throttle, set_throttle) = signal(Throttle::load());
+    let (now, set_now) = signal(js_sys::Date::now());
+    let error_ref = NodeRef::<leptos::html::P>::new();
+
+    // Ticks the cool-down countdown
+    if let Ok(handle) = set_interval_with_handle(move || set_now.set(js_sys::Date::now()), Duration::from_secs(1)) {
+        on_cleanup(move || handle.clear());
+    }
+
+    let field_errors = Memo::new(move |_| validate(&form_data.get()));
+    let lock_remaining = Memo::new(move |_| throttle.get().remaining_secs(now.get()));
+
+    // Move focus to the error so screen readers announce it
+    Effect::new(move |_| {
+        if !err.get().is_empty() && let Some(el) = error_ref.get() {
+            let _ = el.focus();
+        }
+    });
 
     let handle_input_change = move |ev: Event| {
         let name = event_target::<web_sys::HtmlInputElement>(&ev).name();
@@ -34,89 +125,179 @@ pub fn Login() -> impl IntoView {
         match name.as_str() {
             "username" => data.username = value,
             "password" => data.password = value,
+            "remember" => data.remember = event_target_checked(&ev),
             _ => {}
         }
         set_form_data.set(data);
+        set_err.set(String::new());
     };
 
+    let handle_caps_lock = move |ev: KeyboardEvent| {
+        set_caps_lock.set(ev.get_modifier_state("CapsLock"));
+    };
+
+    let login_action = Action::new_local(|(request, remember): &(LoginRequest, bool)| {
+        let request = request.clone();
+        let remember = *remember;
+        async move { (try_login(request).await.map_err(|e| e.to_string()), remember) }
+    });
+
+    Effect::new(move |_| match login_action.value().get() {
+        Some((Ok(result), remember)) => {
+            LocalStorage::delete(THROTTLE_KEY);
+            set_form_data.set(FormData::default());
+            session.sign_in(result.token, result.refresh_token, remember);
+            if let Some(user_id) = session.user_id() {
+                // Runs in the background, the guest cart stays put if it fails
+                spawn_local(async move {
+                    match guest_cart.merge_into_account(user_id).await {
+                        Ok(Some(cart)) => toaster.success(format!("Your cart items were saved to cart #{}.", cart.id)),
+                        Ok(None) => {}
+                        Err(e) => toaster.error(format!("Could not save your cart items: {e}")),
+                    }
+                });
+            }
+            navigate(&AppRoute::Dashboard.href(), Default::default());
+        }
+        Some((Err(error_msg), _)) => {
+            // Storage is the source of truth, another tab may have failed too
+            let updated = Throttle::load().record_failure(js_sys::Date::now());
+            updated.save();
+            set_throttle.set(updated);
+            set_err.set(error_msg);
+        }
+        None => {}
+    });
+
     let handle_form_submit = move |ev: SubmitEvent| {
         ev.prevent_default();
-        let current_data = form_data.get();
-        let navigate_clone = navigate.clone();
-        set_is_submitting.set(true);
+        if login_action.pending().get_untracked() || lock_remaining.get_untracked() > 0 {
+            return;
+        }
 
-        spawn_local(async move {
-            let login_request = LoginRequest {
+        set_touched.set((true, true));
+        set_err.set(String::new());
+        if !field_errors.get_untracked().is_empty() {
+            return;
+        }
+
+        let current_data = form_data.get();
+        login_action.dispatch((
+            LoginRequest {
                 username: current_data.username,
                 password: current_data.password,
-            };
-            let response = try_login(login_request).await.map_err(|e| e.to_string());
-
-            match response {
-                Ok(result) => {
-                    set_form_data.set(FormData::default());
-                    set_cookie.set(Some(result.token));
-                    navigate_clone("/dashboard", Default::default());
-                }
-                Err(error_msg) => {
-                    set_err.set(error_msg);
-                }
-            }
-        });
-        set_is_submitting.set(false);
+            },
+            current_data.remember,
+        ));
     };
 
+    let input_class = "mt-2 rounded-md ring ring-gray-200 focus:ring-2 focus:ring-indigo-600 outline-none px-3 py-3 w-full aria-invalid:ring-red-400";
+
     view! {
     <div class="flex items-center justify-center w-full px-4 py-8">
         <form
             class="flex w-full flex-col max-w-1/3"
             on:submit=handle_form_submit
+            novalidate
         >
             <h2 class="text-4xl font-medium text-gray-900">"Sign in"</h2>
             <p class="mt-4 text-base text-gray-500/90">
-                "Please enter email and password to access."
+                "Please enter username and password to access."
+            </p>
+            <p
+                node_ref=error_ref
+                role="alert"
+                tabindex="-1"
+                class="mt-4 text-red-400 text-sm italic outline-none"
+                class:hidden=move || err.get().is_empty()
+            >
+                "Error: " {move || err.get()}
             </p>
-            {move || match err.get() {
-                err_msg if !err_msg.is_empty() => view! {
-                    <p class="text-red-400 text-sm italic">"Error: " {err_msg}</p>
-                }.into_any(),
-                _ => view! { <></> }.into_any(),
-            }}
+            <Show when=move || { lock_remaining.get() > 0 }>
+                <p role="status" class="mt-4 rounded-md bg-amber-50 px-3 py-2 text-sm text-amber-700">
+                    {move || format!("Too many failed attempts. Try again in {}s.", lock_remaining.get())}
+                </p>
+            </Show>
             <div class="mt-10">
-                <label class="font-medium">"Email"</label>
+                <label for="username" class="font-medium">"Username"</label>
                 <input
                     placeholder="Please enter your username"
-                    class="mt-2 rounded-md ring ring-gray-200 focus:ring-2 focus:ring-indigo-600 outline-none px-3 py-3 w-full"
+                    class=input_class
                     required
                     type="text"
                     name="username"
                     id="username"
+                    autocomplete="username"
+                    aria-describedby="username-error"
+                    aria-invalid=move || (touched.get().0 && field_errors.get().username.is_some()).to_string()
                     prop:value = move || form_data.get().username
                     on:input = handle_input_change
+                    on:blur = move |_| set_touched.update(|t| t.0 = true)
                 />
+                <p id="username-error" class="mt-1 text-xs text-red-500">
+                    {move || if touched.get().0 { field_errors.get().username } else { None }}
+                </p>
             </div>
 
             <div class="mt-6">
-                <label class="font-medium">"Password"</label>
+                <label for="password" class="font-medium">"Password"</label>
+                <div class="relative">
+                    <input
+                        placeholder="Please enter your password"
+                        class=format!("{input_class} pr-20")
+                        required
+                        type=move || if show_password.get() { "text" } else { "password" }
+                        name="password"
+                        id="password"
+                        autocomplete="current-password"
+                        aria-describedby="password-error password-caps-lock"
+                        aria-invalid=move || (touched.get().1 && field_errors.get().password.is_some()).to_string()
+                        prop:value = move || form_data.get().password
+                        on:input = handle_input_change
+                        on:keydown = handle_caps_lock
+                        on:keyup = handle_caps_lock
+                        on:blur = move |_| {
+                            set_touched.update(|t| t.1 = true);
+                            set_caps_lock.set(false);
+                        }
+                    />
+                    <button
+                        type="button"
+                        class="absolute right-3 top-1/2 mt-1 -translate-y-1/2 text-sm text-indigo-600 hover:underline cursor-pointer"
+                        aria-controls="password"
+                        aria-pressed=move || show_password.get().to_string()
+                        on:click=move |_| set_show_password.update(|show| *show = !*show)
+                    >
+                        {move || if show_password.get() { "Hide" } else { "Show" }}
+                    </button>
+                </div>
+                <p id="password-caps-lock" class="mt-1 text-xs text-amber-600" aria-live="polite">
+                    {move || caps_lock.get().then_some("Caps Lock is on.")}
+                </p>
+                <p id="password-error" class="mt-1 text-xs text-red-500">
+                    {move || if touched.get().1 { field_errors.get().password } else { None }}
+                </p>
+            </div>
+
+            <label class="mt-4 flex items-center gap-2 text-sm text-gray-600 cursor-pointer">
                 <input
-                    placeholder="Please enter your password"
-                    class="mt-2 rounded-md ring ring-gray-200 focus:ring-2 focus:ring-indigo-600 outline-none px-3 py-3 w-full"
-                    required
-                    type="password"
-                    name="password"
-                    id="password"
-                    prop:value = move || form_data.get().password
-                    on:input = handle_input_change
-                    autocomplete
+                    type="checkbox"
+                    name="remember"
+                    id="remember"
+                    class="size-4 accent-indigo-600"
+                    prop:checked = move || form_data.get().remember
+                    on:change = handle_input_change
                 />
-            </div>
+                "Remember me"
+            </label>
 
             <button
                 type="submit"
-                disabled=is_submitting
+                disabled=move || login_action.pending().get() || lock_remaining.get() > 0
+                aria-busy=move || login_action.pending().get().to_string()
                 class="mt-8 py-3 w-full cursor-pointer rounded-md bg-indigo-600 text-white transition hover:bg-indigo-700 flex items-center justify-center gap-2 disabled:bg-indigo-300 disabled:cursor-not-allowed"
             >
-                {move || if is_submitting.get() {
+                {move || if login_action.pending().get() {
                     view! {
                         <IconView
                             icon=Icon::LOADER_CIRCLE.clone()
@@ -131,7 +312,7 @@ pub fn Login() -> impl IntoView {
                         </IconView>
                     }
                 }}
-                "Login"
+                {move || if login_action.pending().get() { "Signing in..." } else { "Login" }}
             </button>
             <p class="text-center py-8">
                 "Don't have an account? " <A href="/signup" attr:class="text-indigo-600 hover:underline">"Sign up"</A>
@@ -3,4 +3,17 @@
 pub mod home;
 pub mod login;
 pub mod dashboard;
-pub mod not_found;
\ No newline at end of file
+pub mod about;
+pub mod cart_detail;
+pub mod carts;
+pub mod checkout;
+pub mod contact;
+pub mod forbidden;
+pub mod not_found;
+pub mod privacy;
+pub mod product_detail;
+pub mod product_form;
+pub mod products;
+pub mod search;
+pub mod user_form;
+pub mod users;
\ No newline at end of file
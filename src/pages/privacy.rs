@@ -0,0 +1,19 @@
+use leptos::prelude::*;
+
+// Rendered from content/privacy.md by build.rs
+const PRIVACY_HTML: &str = include_str!(concat!(env!("OUT_DIR"), "/privacy.html"));
+
+#[component]
+pub fn Privacy() -> impl IntoView {
+    view! {
+        <article
+            class="mx-auto max-w-3xl space-y-4 px-6 py-12 text-sm leading-relaxed text-muted-foreground \
+                   [&_h1]:text-3xl [&_h1]:font-semibold [&_h1]:text-foreground \
+                   [&_h2]:pt-4 [&_h2]:text-xl [&_h2]:font-semibold [&_h2]:text-foreground \
+                   [&_strong]:text-foreground [&_ul]:list-disc [&_ul]:space-y-1 [&_ul]:pl-6 \
+                   [&_table]:w-full [&_table]:border-collapse [&_th]:border [&_th]:bg-muted/50 [&_th]:px-3 [&_th]:py-2 [&_th]:text-left [&_th]:text-foreground \
+                   [&_td]:border [&_td]:px-3 [&_td]:py-2"
+            inner_html=PRIVACY_HTML
+        ></article>
+    }
+}
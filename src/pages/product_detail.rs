@@ -0,0 +1,239 @@
+use crate::api::cart_api::get_all_carts;
+use crate::api::product_api::{Product, Rating, delete_a_product, get_a_product};
+use crate::components::base::button::{Button, ButtonSize, ButtonVariant};
+use crate::components::ui::{
+    add_to_cart_button::AddToCartButton,
+    confirm_dialog::ConfirmDialog,
+    route_error_boundary::LoadError,
+    skeleton::{Skeleton, SkeletonText},
+    toast::use_toaster,
+};
+use crate::routes::AppRoute;
+use crate::utils::currency::format_price;
+use leptos::prelude::*;
+use leptos_router::components::A;
+use leptos_router::hooks::{use_navigate, use_params_map};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ProductDetailVariant {
+    /// Private route, with carts and edit/delete actions
+    Admin,
+    /// Public route under `PublicLayout`
+    Storefront,
+}
+
+/// Admin view at `/products/:id`.
+#[component]
+pub fn ProductDetailPage() -> impl IntoView {
+    view! { <ProductDetail variant=ProductDetailVariant::Admin /> }
+}
+
+/// Storefront view at `/shop/:id`.
+#[component]
+pub fn ShopProductPage() -> impl IntoView {
+    view! { <ProductDetail variant=ProductDetailVariant::Storefront /> }
+}
+
+#[component]
+fn RatingStars(rating: Rating) -> impl IntoView {
+    let filled = rating.rate.round().clamp(0.0, 5.0) as usize;
+
+    view! {
+        <div class="flex items-center gap-2 text-sm">
+            <span class="text-amber-500" aria-hidden="true">
+                {"★".repeat(filled)}<span class="text-gray-300">{"★".repeat(5 - filled)}</span>
+            </span>
+            <span class="text-muted-foreground">
+                {format!("{:.1} out of 5 ({} reviews)", rating.rate, rating.count)}
+            </span>
+        </div>
+    }
+}
+
+#[component]
+pub fn ProductDetail(variant: ProductDetailVariant) -> impl IntoView {
+    let params = use_params_map();
+    let product_id = Memo::new(move |_| params.read().get("id").and_then(|id| id.parse::<u32>().ok()));
+
+    let product = LocalResource::new(move || {
+        let product_id = product_id.get();
+        async move {
+            match product_id {
+                Some(product_id) => get_a_product(product_id).await.map_err(|e| e.to_string()),
+                None => Err("Invalid product id.".to_string()),
+            }
+        }
+    });
+
+    let back_link = match variant {
+        ProductDetailVariant::Admin => (AppRoute::Products.href(), "← Back to products"),
+        ProductDetailVariant::Storefront => (AppRoute::Home.href(), "← Back to shop"),
+    };
+
+    view! {
+        <section class=match variant {
+            ProductDetailVariant::Admin => "p-6 space-y-6",
+            ProductDetailVariant::Storefront => "mx-auto max-w-6xl px-6 py-10 space-y-6",
+        }>
+            <A href=back_link.0 attr:class="text-sm text-muted-foreground hover:text-foreground">{back_link.1}</A>
+
+            <Transition fallback=|| view! { <ProductDetailSkeleton /> }>
+                {move || Suspend::new(async move {
+                    Ok::<_, LoadError>(match product.await {
+                        Err(err_msg) => return Err(LoadError::new("this product", err_msg)),
+                        Ok(product) => view! {
+                            <div class="grid gap-10 md:grid-cols-2">
+                                <div class="flex items-center justify-center rounded-lg border bg-white p-8">
+                                    <img src=product.image.clone() alt=product.title.clone() class="max-h-[28rem] w-full object-contain" />
+                                </div>
+                                <div class="space-y-4">
+                                    <p class="text-xs uppercase tracking-wide text-muted-foreground">{product.category.clone()}</p>
+                                    <h1 class="text-3xl font-semibold">{product.title.clone()}</h1>
+                                    {product.rating.map(|rating| view! { <RatingStars rating=rating /> })}
+                                    <p class="text-3xl font-bold">{format_price(product.price)}</p>
+                                    <p class="leading-relaxed text-muted-foreground">{product.description.clone()}</p>
+                                    {match variant {
+                                        ProductDetailVariant::Admin => view! { <AdminActions product=product.clone() /> }.into_any(),
+                                        ProductDetailVariant::Storefront => view! {
+                                            <AddToCartButton product_id=product.id title=product.title.clone() size=ButtonSize::Lg class="mt-4".to_string() />
+                                        }.into_any(),
+                                    }}
+                                </div>
+                            </div>
+                            {(variant == ProductDetailVariant::Admin).then(|| view! { <ProductCarts product_id=product.id /> })}
+                        }.into_any(),
+                    })
+                })}
+            </Transition>
+        </section>
+    }
+}
+
+#[component]
+fn ProductDetailSkeleton() -> impl IntoView {
+    view! {
+        <div class="grid gap-10 md:grid-cols-2" role="status">
+            <span class="sr-only">"Loading product..."</span>
+            <Skeleton class="h-[28rem] rounded-lg" />
+            <div class="space-y-4">
+                <Skeleton class="h-3 w-24" />
+                <Skeleton class="h-9 w-3/4" />
+                <Skeleton class="h-4 w-32" />
+                <Skeleton class="h-9 w-28" />
+                <SkeletonText lines=4 />
+            </div>
+        </div>
+    }
+}
+
+#[component]
+fn AdminActions(product: Product) -> impl IntoView {
+    let navigate = use_navigate();
+    let toaster = use_toaster();
+    let (confirm_open, set_confirm_open) = signal(false);
+    let product_id = product.id;
+    let title = product.title.clone();
+
+    let delete_action = Action::new_local(move |_: &()| async move {
+        delete_a_product(product_id).await.map_err(|e| e.to_string())
+    });
+
+    Effect::new(move |_| match delete_action.value().get() {
+        Some(Ok(_)) => {
+            set_confirm_open.set(false);
+            toaster.success(format!("{title} deleted."));
+            navigate(&AppRoute::Products.href(), Default::default());
+        }
+        Some(Err(err)) => {
+            set_confirm_open.set(false);
+            toaster.error(format!("Delete failed: {err}"));
+        }
+        None => {}
+    });
+
+    view! {
+        <div class="flex gap-2 pt-4">
+            <A href=AppRoute::EditProduct { id: product_id }.href() attr:class="inline-flex h-9 items-center rounded-md bg-primary px-4 text-sm font-medium text-primary-foreground hover:bg-primary/90">
+                "Edit"
+            </A>
+            <Button variant=ButtonVariant::Destructive on_click=move || set_confirm_open.set(true)>"Delete"</Button>
+        </div>
+        <ConfirmDialog
+            open=confirm_open
+            title="Delete product?".to_string()
+            description=format!("{} will be removed from the catalogue. This cannot be undone.", product.title)
+            pending=delete_action.pending()
+            on_confirm=move || {
+                delete_action.dispatch(());
+            }
+            on_cancel=move || set_confirm_open.set(false)
+        />
+    }
+}
+
+/// Carts that contain the product, with the quantity ordered in each.
+#[component]
+fn ProductCarts(product_id: u32) -> impl IntoView {
+    let carts = LocalResource::new(move || async move {
+        get_all_carts().await.map_err(|e| e.to_string()).map(|carts| {
+            carts
+                .into_iter()
+                .filter_map(|cart| {
+                    let quantity = cart
+                        .products
+                        .iter()
+                        .filter(|item| item.product_id == product_id)
+                        .map(|item| item.quantity)
+                        .sum::<u32>();
+                    (quantity > 0).then_some((cart, quantity))
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+
+    view! {
+        <div class="space-y-3">
+            <h2 class="text-lg font-semibold">"In carts"</h2>
+            <Transition fallback=|| view! { <SkeletonText lines=3 class="max-w-md" /> }>
+                {move || Suspend::new(async move {
+                    match carts.await {
+                        Err(err_msg) => view! {
+                            <p class="text-sm text-destructive" role="alert">"Could not load carts: " {err_msg}</p>
+                        }.into_any(),
+                        Ok(list) if list.is_empty() => view! {
+                            <p class="text-sm text-muted-foreground">"This product is not in any cart."</p>
+                        }.into_any(),
+                        Ok(list) => view! {
+                            <div class="overflow-x-auto rounded-md border">
+                                <table class="w-full text-sm">
+                                    <thead class="bg-muted/50 text-muted-foreground">
+                                        <tr>
+                                            <th scope="col" class="px-4 py-3 text-left font-medium">"Cart"</th>
+                                            <th scope="col" class="px-4 py-3 text-left font-medium">"User"</th>
+                                            <th scope="col" class="px-4 py-3 text-left font-medium">"Date"</th>
+                                            <th scope="col" class="px-4 py-3 text-right font-medium">"Quantity"</th>
+                                        </tr>
+                                    </thead>
+                                    <tbody>
+                                        {list.into_iter().map(|(cart, quantity)| view! {
+                                            <tr class="border-t">
+                                                <td class="px-4 py-2">
+                                                    <A href=AppRoute::CartDetail { id: cart.id }.href() attr:class="font-medium hover:underline">{format!("#{}", cart.id)}</A>
+                                                </td>
+                                                <td class="px-4 py-2">
+                                                    <A href=AppRoute::EditUser { id: cart.user_id }.href() attr:class="hover:underline">{format!("User #{}", cart.user_id)}</A>
+                                                </td>
+                                                <td class="px-4 py-2 text-muted-foreground">{cart.date.get(..10).unwrap_or(&cart.date).to_string()}</td>
+                                                <td class="px-4 py-2 text-right tabular-nums">{quantity}</td>
+                                            </tr>
+                                        }).collect_view()}
+                                    </tbody>
+                                </table>
+                            </div>
+                        }.into_any(),
+                    }
+                })}
+            </Transition>
+        </div>
+    }
+}
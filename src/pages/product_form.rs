@@ -0,0 +1,390 @@
+use crate::api::product_api::{
+    Product, add_a_product, get_a_product, get_all_categories, update_a_product,
+};
+use crate::components::base::button::{Button, ButtonVariant};
+use crate::components::base::icons::*;
+use crate::components::ui::{product_card::ProductCard, toast::use_toaster};
+use crate::routes::AppRoute;
+use crate::utils::currency::{CURRENCY, parse_price};
+use crate::utils::unsaved_changes::use_unsaved_changes_prompt;
+use gloo_file::{Blob, futures::read_as_data_url};
+use leptos::{
+    ev::{Event, SubmitEvent},
+    prelude::*,
+    task::spawn_local,
+    web_sys,
+};
+use leptos_router::components::A;
+use leptos_router::hooks::{use_navigate, use_params_map};
+
+// Uploaded images are inlined as data URLs, keep them reasonably small
+const MAX_IMAGE_BYTES: f64 = 2.0 * 1024.0 * 1024.0;
+
+#[derive(Default, Debug, Clone, PartialEq)]
+struct FormData {
+    title: String,
+    // Raw text of the price input, parsed on validation
+    price: String,
+    description: String,
+    category: String,
+    image: String,
+}
+
+impl From<Product> for FormData {
+    fn from(product: Product) -> Self {
+        Self {
+            title: product.title,
+            price: format!("{:.*}", CURRENCY.decimals, product.price),
+            description: product.description,
+            category: product.category,
+            image: product.image,
+        }
+    }
+}
+
+impl FormData {
+    /// Applies the form to `loaded`, fields the form does not edit (id,
+    /// rating) are sent back unchanged.
+    fn to_product(&self, loaded: Product) -> Product {
+        Product {
+            title: self.title.trim().to_string(),
+            price: parse_price(&self.price).unwrap_or_default(),
+            description: self.description.trim().to_string(),
+            category: self.category.clone(),
+            image: self.image.trim().to_string(),
+            ..loaded
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq)]
+struct FieldErrors {
+    title: Option<&'static str>,
+    price: Option<&'static str>,
+    description: Option<&'static str>,
+    category: Option<&'static str>,
+    image: Option<&'static str>,
+}
+
+impl FieldErrors {
+    fn is_empty(&self) -> bool {
+        self.title.is_none()
+            && self.price.is_none()
+            && self.description.is_none()
+            && self.category.is_none()
+            && self.image.is_none()
+    }
+}
+
+fn validate(data: &FormData) -> FieldErrors {
+    let title = data.title.trim().is_empty().then_some("Title is required.");
+
+    let price = match parse_price(&data.price) {
+        _ if data.price.trim().is_empty() => Some("Price is required."),
+        None => Some("Enter a valid amount."),
+        Some(price) if price <= 0.0 => Some("Price must be greater than zero."),
+        Some(_) => None,
+    };
+
+    let description = data.description.trim().is_empty().then_some("Description is required.");
+    let category = data.category.is_empty().then_some("Pick a category.");
+
+    let image = data.image.trim();
+    let image = if image.is_empty() {
+        Some("Add an image URL or upload a file.")
+    } else if !(image.starts_with("https://") || image.starts_with("http://") || image.starts_with("data:image/")) {
+        Some("Image must be an http(s) URL.")
+    } else {
+        None
+    };
+
+    FieldErrors { title, price, description, category, image }
+}
+
+/// Create form at `/products/new`, edit form at `/products/:id/edit`.
+#[component]
+pub fn ProductForm() -> impl IntoView {
+    let params = use_params_map();
+    let navigate = use_navigate();
+    let toaster = use_toaster();
+
+    let product_id = Memo::new(move |_| params.read().get("id").and_then(|id| id.parse::<u32>().ok()));
+    let is_edit = move || params.read().get("id").is_some();
+
+    let product = LocalResource::new(move || {
+        let product_id = product_id.get();
+        async move {
+            match product_id {
+                Some(product_id) => get_a_product(product_id).await.map(Some).map_err(|e| e.to_string()),
+                None => Ok(None),
+            }
+        }
+    });
+    let categories = LocalResource::new(|| async { get_all_categories().await.unwrap_or_default() });
+
+    let (form_data, set_form_data) = signal(FormData::default());
+    // Last saved (or loaded) values, the form is dirty while it differs
+    let (initial, set_initial) = signal(FormData::default());
+    let (submitted, set_submitted) = signal(false);
+    let (submit_error, set_submit_error) = signal(None::<String>);
+    let (upload_error, set_upload_error) = signal(None::<String>);
+
+    Effect::new(move |_| {
+        if let Some(Ok(Some(product))) = product.get() {
+            let data = FormData::from(product);
+            set_initial.set(data.clone());
+            set_form_data.set(data);
+        }
+    });
+
+    let field_errors = Memo::new(move |_| validate(&form_data.get()));
+    let dirty = Signal::derive(move || form_data.get() != initial.get());
+    use_unsaved_changes_prompt(dirty);
+
+    let loaded = move || product.get().and_then(Result::ok).flatten().unwrap_or_default();
+    let preview = Signal::derive(move || form_data.get().to_product(loaded()));
+
+    let save_action = Action::new_local(move |data: &FormData| {
+        let product = data.to_product(untrack(loaded));
+        async move {
+            if product_id.get_untracked().is_some() {
+                update_a_product(product).await
+            } else {
+                add_a_product(product).await
+            }
+            .map_err(|e| e.to_string())
+        }
+    });
+
+    Effect::new(move |_| match save_action.value().get() {
+        Some(Ok(saved)) => {
+            set_initial.set(form_data.get_untracked());
+            toaster.success(format!("{} saved.", saved.title));
+            navigate(&AppRoute::Products.href(), Default::default());
+        }
+        Some(Err(err)) => set_submit_error.set(Some(err)),
+        None => {}
+    });
+
+    let handle_form_submit = move |ev: SubmitEvent| {
+        ev.prevent_default();
+        set_submitted.set(true);
+        set_submit_error.set(None);
+        if field_errors.get_untracked().is_empty() && !save_action.pending().get_untracked() {
+            save_action.dispatch(form_data.get_untracked());
+        }
+    };
+
+    let handle_image_upload = move |ev: Event| {
+        set_upload_error.set(None);
+        let Some(file) = event_target::<web_sys::HtmlInputElement>(&ev)
+            .files()
+            .and_then(|files| files.get(0))
+        else {
+            return;
+        };
+        if !file.type_().starts_with("image/") {
+            set_upload_error.set(Some("Only image files can be uploaded.".to_string()));
+            return;
+        }
+        if file.size() > MAX_IMAGE_BYTES {
+            set_upload_error.set(Some("Images must be 2 MB or smaller.".to_string()));
+            return;
+        }
+
+        spawn_local(async move {
+            match read_as_data_url(&Blob::from(file)).await {
+                Ok(data_url) => set_form_data.update(|data| data.image = data_url),
+                Err(err) => set_upload_error.set(Some(err.to_string())),
+            }
+        });
+    };
+
+    let show_error = move |error: fn(&FieldErrors) -> Option<&'static str>| {
+        move || submitted.get().then(|| error(&field_errors.get())).flatten()
+    };
+
+    let input_class = "mt-1 w-full rounded-md border bg-background px-3 py-2 text-sm outline-none focus:ring-2 focus:ring-ring/50 aria-invalid:border-destructive";
+
+    view! {
+        <section class="p-6 space-y-6">
+            <div>
+                <A href=AppRoute::Products.href() attr:class="text-sm text-muted-foreground hover:text-foreground">"← Back to products"</A>
+                <h1 class="mt-2 text-2xl font-semibold">
+                    {move || if is_edit() { "Edit product" } else { "New product" }}
+                </h1>
+            </div>
+
+            {move || match product.get() {
+                None if is_edit() => view! {
+                    <div class="flex items-center gap-2 text-muted-foreground" role="status">
+                        <IconView icon=Icon::LOADER_CIRCLE.clone() class="animate-spin" />
+                        "Loading product..."
+                    </div>
+                }.into_any(),
+                Some(Err(err_msg)) => view! {
+                    <p class="rounded-md border border-destructive/40 bg-destructive/5 p-4 text-sm text-destructive" role="alert">
+                        "Could not load product: " {err_msg}
+                    </p>
+                }.into_any(),
+                Some(Ok(None)) if is_edit() => view! {
+                    <p class="text-sm text-muted-foreground">"This product does not exist."</p>
+                }.into_any(),
+                _ => view! {
+                    <div class="grid gap-8 lg:grid-cols-[minmax(0,1fr)_20rem]">
+                        <form class="space-y-4" on:submit=handle_form_submit novalidate>
+                            <div>
+                                <label for="title" class="text-sm font-medium">"Title"</label>
+                                <input
+                                    id="title"
+                                    class=input_class
+                                    aria-describedby="title-error"
+                                    aria-invalid=move || show_error(|e| e.title)().is_some().to_string()
+                                    prop:value=move || form_data.get().title
+                                    on:input=move |ev| set_form_data.update(|data| data.title = event_target_value(&ev))
+                                />
+                                <p id="title-error" class="mt-1 text-xs text-destructive">{show_error(|e| e.title)}</p>
+                            </div>
+
+                            <div class="grid gap-4 sm:grid-cols-2">
+                                <div>
+                                    <label for="price" class="text-sm font-medium">{format!("Price ({})", CURRENCY.code)}</label>
+                                    <div class="relative">
+                                        <span class="pointer-events-none absolute left-3 top-1/2 mt-0.5 -translate-y-1/2 text-sm text-muted-foreground">
+                                            {CURRENCY.symbol}
+                                        </span>
+                                        <input
+                                            id="price"
+                                            inputmode="decimal"
+                                            placeholder="0.00"
+                                            class=format!("{input_class} pl-7 tabular-nums")
+                                            aria-describedby="price-error"
+                                            aria-invalid=move || show_error(|e| e.price)().is_some().to_string()
+                                            prop:value=move || form_data.get().price
+                                            on:input=move |ev| set_form_data.update(|data| data.price = event_target_value(&ev))
+                                            on:blur=move |_| set_form_data.update(|data| {
+                                                // Normalise `1,234.5` to `1234.50`
+                                                if let Some(price) = parse_price(&data.price) {
+                                                    data.price = format!("{:.*}", CURRENCY.decimals, price);
+                                                }
+                                            })
+                                        />
+                                    </div>
+                                    <p id="price-error" class="mt-1 text-xs text-destructive">{show_error(|e| e.price)}</p>
+                                </div>
+
+                                <div>
+                                    <label for="category" class="text-sm font-medium">"Category"</label>
+                                    <select
+                                        id="category"
+                                        class=input_class
+                                        aria-describedby="category-error"
+                                        aria-invalid=move || show_error(|e| e.category)().is_some().to_string()
+                                        on:change=move |ev| set_form_data.update(|data| data.category = event_target_value(&ev))
+                                    >
+                                        <option value="" disabled selected=move || form_data.get().category.is_empty()>
+                                            {move || if categories.get().is_none() { "Loading categories..." } else { "Select a category" }}
+                                        </option>
+                                        {move || {
+                                            let current = form_data.get().category;
+                                            let mut options = categories.get().unwrap_or_default();
+                                            // Keep a category the backend no longer lists selectable
+                                            if !current.is_empty() && !options.contains(&current) {
+                                                options.push(current.clone());
+                                            }
+                                            options.into_iter().map(|category| {
+                                                let selected = category == current;
+                                                view! { <option value=category.clone() selected=selected>{category.clone()}</option> }
+                                            }).collect_view()
+                                        }}
+                                    </select>
+                                    <p id="category-error" class="mt-1 text-xs text-destructive">{show_error(|e| e.category)}</p>
+                                </div>
+                            </div>
+
+                            <div>
+                                <label for="description" class="text-sm font-medium">"Description"</label>
+                                <textarea
+                                    id="description"
+                                    rows="5"
+                                    class=input_class
+                                    aria-describedby="description-error"
+                                    aria-invalid=move || show_error(|e| e.description)().is_some().to_string()
+                                    prop:value=move || form_data.get().description
+                                    on:input=move |ev| set_form_data.update(|data| data.description = event_target_value(&ev))
+                                />
+                                <p id="description-error" class="mt-1 text-xs text-destructive">{show_error(|e| e.description)}</p>
+                            </div>
+
+                            <div>
+                                <label for="image" class="text-sm font-medium">"Image"</label>
+                                <div class="flex gap-4">
+                                    <div class="flex size-20 shrink-0 items-center justify-center overflow-hidden rounded-md border bg-white">
+                                        {move || {
+                                            let image = form_data.get().image;
+                                            (!image.is_empty()).then(|| view! { <img src=image alt="Image preview" class="h-full w-full object-contain" /> })
+                                        }}
+                                    </div>
+                                    <div class="flex-1 space-y-2">
+                                        <input
+                                            id="image"
+                                            type="url"
+                                            placeholder="https://"
+                                            class=input_class
+                                            aria-describedby="image-error"
+                                            aria-invalid=move || show_error(|e| e.image)().is_some().to_string()
+                                            prop:value=move || form_data.get().image
+                                            on:input=move |ev| set_form_data.update(|data| data.image = event_target_value(&ev))
+                                        />
+                                        <Show when=move || form_data.with(|data| data.image.starts_with("data:"))>
+                                            <p class="text-xs text-muted-foreground">"Using the uploaded file. Enter a URL to replace it."</p>
+                                        </Show>
+                                        <input
+                                            type="file"
+                                            accept="image/*"
+                                            aria-label="Upload image"
+                                            class="block w-full text-sm text-muted-foreground file:mr-3 file:cursor-pointer file:rounded-md file:border-0 file:bg-secondary file:px-3 file:py-1.5 file:text-sm"
+                                            on:change=handle_image_upload
+                                        />
+                                    </div>
+                                </div>
+                                <p id="image-error" class="mt-1 text-xs text-destructive">
+                                    {move || upload_error.get().or_else(|| show_error(|e| e.image)().map(String::from))}
+                                </p>
+                            </div>
+
+                            {move || submit_error.get().map(|err| view! {
+                                <p class="text-sm text-destructive" role="alert">"Saving failed: " {err}</p>
+                            })}
+
+                            <div class="flex items-center gap-2 pt-2">
+                                <Button attr:type="submit" attr:disabled=move || save_action.pending().get()>
+                                    {move || save_action.pending().get().then(|| view! {
+                                        <IconView icon=Icon::LOADER_CIRCLE.clone() class="animate-spin" />
+                                    })}
+                                    {move || if is_edit() { "Save changes" } else { "Create product" }}
+                                </Button>
+                                <Button
+                                    variant=ButtonVariant::Ghost
+                                    attr:type="button"
+                                    attr:disabled=move || !dirty.get()
+                                    on_click=move || set_form_data.set(initial.get_untracked())
+                                >
+                                    "Reset"
+                                </Button>
+                                <span class="text-xs text-muted-foreground" class:hidden=move || !dirty.get()>
+                                    "Unsaved changes"
+                                </span>
+                            </div>
+                        </form>
+
+                        <aside class="space-y-2">
+                            <p class="text-sm font-medium text-muted-foreground">"Storefront preview"</p>
+                            <ProductCard product=preview />
+                        </aside>
+                    </div>
+                }.into_any(),
+            }}
+        </section>
+    }
+}
@@ -0,0 +1,426 @@
+use crate::api::product_api::{Product, delete_a_product, get_all_products};
+use crate::components::base::button::{Button, ButtonSize, ButtonVariant};
+use crate::components::base::icons::*;
+use crate::components::ui::{
+    confirm_dialog::ConfirmDialog,
+    route_error_boundary::LoadError,
+    skeleton::SkeletonGrid,
+    toast::use_toaster,
+};
+use crate::routes::AppRoute;
+use crate::utils::currency::{format_price, parse_price};
+use futures::future::join_all;
+use leptos::prelude::*;
+use leptos_router::components::A;
+use std::collections::{BTreeSet, HashSet};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ViewMode {
+    Grid,
+    Table,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum SortOrder {
+    TitleAsc,
+    TitleDesc,
+    PriceAsc,
+    PriceDesc,
+}
+
+impl SortOrder {
+    const ALL: [SortOrder; 4] = [
+        SortOrder::TitleAsc,
+        SortOrder::TitleDesc,
+        SortOrder::PriceAsc,
+        SortOrder::PriceDesc,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            SortOrder::TitleAsc => "Title (A-Z)",
+            SortOrder::TitleDesc => "Title (Z-A)",
+            SortOrder::PriceAsc => "Price (low to high)",
+            SortOrder::PriceDesc => "Price (high to low)",
+        }
+    }
+
+    fn value(&self) -> &'static str {
+        match self {
+            SortOrder::TitleAsc => "title-asc",
+            SortOrder::TitleDesc => "title-desc",
+            SortOrder::PriceAsc => "price-asc",
+            SortOrder::PriceDesc => "price-desc",
+        }
+    }
+
+    fn from_value(value: &str) -> Self {
+        Self::ALL
+            .into_iter()
+            .find(|order| order.value() == value)
+            .unwrap_or(SortOrder::TitleAsc)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Filters {
+    query: String,
+    category: Option<String>,
+    min_price: Option<f64>,
+    max_price: Option<f64>,
+}
+
+impl Filters {
+    fn matches(&self, product: &Product) -> bool {
+        let query = self.query.trim().to_lowercase();
+        (query.is_empty()
+            || product.title.to_lowercase().contains(&query)
+            || product.description.to_lowercase().contains(&query))
+            && self.category.as_ref().is_none_or(|category| &product.category == category)
+            && self.min_price.is_none_or(|min| product.price >= min)
+            && self.max_price.is_none_or(|max| product.price <= max)
+    }
+}
+
+fn sort_products(products: &mut [Product], order: SortOrder) {
+    products.sort_by(|a, b| match order {
+        SortOrder::TitleAsc => a.title.to_lowercase().cmp(&b.title.to_lowercase()),
+        SortOrder::TitleDesc => b.title.to_lowercase().cmp(&a.title.to_lowercase()),
+        SortOrder::PriceAsc => a.price.total_cmp(&b.price),
+        SortOrder::PriceDesc => b.price.total_cmp(&a.price),
+    });
+}
+
+fn parse_price_filter(value: &str) -> Option<f64> {
+    parse_price(value).filter(|price| *price >= 0.0)
+}
+
+#[component]
+pub fn Products() -> impl IntoView {
+    let toaster = use_toaster();
+    let products = LocalResource::new(|| async { get_all_products().await.map_err(|e| e.to_string()) });
+
+    let (view_mode, set_view_mode) = signal(ViewMode::Grid);
+    let (filters, set_filters) = signal(Filters::default());
+    let (sort_order, set_sort_order) = signal(SortOrder::TitleAsc);
+    let (selected, set_selected) = signal(HashSet::<u32>::new());
+    let (confirm_open, set_confirm_open) = signal(false);
+    // FakeStore does not persist deletes, so removed products are hidden locally
+    let (removed, set_removed) = signal(HashSet::<u32>::new());
+
+    let all_products = Memo::new(move |_| {
+        let removed = removed.get();
+        products
+            .get()
+            .and_then(Result::ok)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|product| !removed.contains(&product.id))
+            .collect::<Vec<_>>()
+    });
+
+    let categories = Memo::new(move |_| {
+        all_products
+            .get()
+            .into_iter()
+            .map(|product| product.category)
+            .collect::<BTreeSet<_>>()
+    });
+
+    let visible_products = Memo::new(move |_| {
+        let filters = filters.get();
+        let mut list: Vec<Product> = all_products
+            .get()
+            .into_iter()
+            .filter(|product| filters.matches(product))
+            .collect();
+        sort_products(&mut list, sort_order.get());
+        list
+    });
+
+    // Filters can hide selected products, which "Delete selected" would then
+    // remove unseen, so a filter change starts a new selection
+    Effect::new(move |previous: Option<Filters>| {
+        let current = filters.get();
+        if previous.is_some_and(|previous| previous != current) {
+            set_selected.set(HashSet::new());
+        }
+        current
+    });
+
+    let all_visible_selected = Signal::derive(move || {
+        let selected = selected.get();
+        visible_products.with(|list| !list.is_empty() && list.iter().all(|product| selected.contains(&product.id)))
+    });
+
+    let toggle_selected = move |id: u32| {
+        set_selected.update(|selected| {
+            if !selected.remove(&id) {
+                selected.insert(id);
+            }
+        });
+    };
+
+    let toggle_all_visible = move || {
+        let ids: Vec<u32> = visible_products.with(|list| list.iter().map(|product| product.id).collect());
+        let select = !all_visible_selected.get_untracked();
+        set_selected.update(|selected| {
+            for id in ids {
+                if select {
+                    selected.insert(id);
+                } else {
+                    selected.remove(&id);
+                }
+            }
+        });
+    };
+
+    let bulk_delete = Action::new_local(|ids: &Vec<u32>| {
+        let ids = ids.clone();
+        async move {
+            let results = join_all(ids.iter().map(|id| delete_a_product(*id))).await;
+            ids.into_iter()
+                .zip(results)
+                .map(|(id, result)| result.map(|_| id).map_err(|_| id))
+                .collect::<Vec<Result<u32, u32>>>()
+        }
+    });
+
+    Effect::new(move |_| {
+        if let Some(results) = bulk_delete.value().get() {
+            let deleted: Vec<u32> = results.iter().filter_map(|result| result.ok()).collect();
+            let failed = results.len() - deleted.len();
+
+            set_removed.update(|removed| removed.extend(deleted.iter().copied()));
+            set_selected.update(|selected| deleted.iter().for_each(|id| {
+                selected.remove(id);
+            }));
+            set_confirm_open.set(false);
+
+            if !deleted.is_empty() {
+                toaster.success(format!("Deleted {} product(s).", deleted.len()));
+            }
+            if failed > 0 {
+                toaster.error(format!("{failed} product(s) could not be deleted."));
+            }
+        }
+    });
+
+    let filter_input_class = "rounded-md border bg-background px-3 py-2 text-sm outline-none focus:ring-2 focus:ring-ring/50";
+
+    view! {
+        <section class="p-6 space-y-4">
+            <div class="flex flex-wrap items-center justify-between gap-4">
+                <div>
+                    <h1 class="text-2xl font-semibold">"Products"</h1>
+                    <p class="text-sm text-muted-foreground">"Browse and manage the store catalogue."</p>
+                </div>
+                <div class="flex items-center gap-2">
+                    <div class="inline-flex rounded-md border p-0.5" role="group" aria-label="View mode">
+                        <Button
+                            variant=ButtonVariant::Ghost
+                            size=ButtonSize::Sm
+                            class="data-[active=true]:bg-accent".to_string()
+                            attr:data-active=move || (view_mode.get() == ViewMode::Grid).to_string()
+                            attr:aria-pressed=move || (view_mode.get() == ViewMode::Grid).to_string()
+                            on_click=move || set_view_mode.set(ViewMode::Grid)
+                        >
+                            <IconView icon=Icon::GRID.clone() />
+                            "Grid"
+                        </Button>
+                        <Button
+                            variant=ButtonVariant::Ghost
+                            size=ButtonSize::Sm
+                            class="data-[active=true]:bg-accent".to_string()
+                            attr:data-active=move || (view_mode.get() == ViewMode::Table).to_string()
+                            attr:aria-pressed=move || (view_mode.get() == ViewMode::Table).to_string()
+                            on_click=move || set_view_mode.set(ViewMode::Table)
+                        >
+                            <IconView icon=Icon::LIST.clone() />
+                            "Table"
+                        </Button>
+                    </div>
+                    <A href=AppRoute::NewProduct.href() attr:class="inline-flex items-center gap-2 rounded-md bg-primary px-4 py-2 text-sm font-medium text-primary-foreground hover:bg-primary/90">
+                        <IconView icon=Icon::PACKAGE_PLUS.clone() />
+                        "New product"
+                    </A>
+                </div>
+            </div>
+
+            <div class="flex flex-wrap items-end gap-3">
+                <input
+                    type="search"
+                    placeholder="Search title or description"
+                    aria-label="Search products"
+                    class=format!("{filter_input_class} w-full max-w-xs")
+                    prop:value=move || filters.get().query
+                    on:input=move |ev| set_filters.update(|filters| filters.query = event_target_value(&ev))
+                />
+                <select
+                    aria-label="Category"
+                    class=filter_input_class
+                    on:change=move |ev| {
+                        let value = event_target_value(&ev);
+                        set_filters.update(|filters| filters.category = (!value.is_empty()).then_some(value));
+                    }
+                >
+                    <option value="">"All categories"</option>
+                    {move || categories.get().into_iter().map(|category| {
+                        let selected = filters.with(|filters| filters.category.as_ref() == Some(&category));
+                        view! { <option value=category.clone() selected=selected>{category.clone()}</option> }
+                    }).collect_view()}
+                </select>
+                <input
+                    type="number"
+                    min="0"
+                    step="0.01"
+                    placeholder="Min price"
+                    aria-label="Minimum price"
+                    class=format!("{filter_input_class} w-28")
+                    on:input=move |ev| set_filters.update(|filters| filters.min_price = parse_price_filter(&event_target_value(&ev)))
+                />
+                <input
+                    type="number"
+                    min="0"
+                    step="0.01"
+                    placeholder="Max price"
+                    aria-label="Maximum price"
+                    class=format!("{filter_input_class} w-28")
+                    on:input=move |ev| set_filters.update(|filters| filters.max_price = parse_price_filter(&event_target_value(&ev)))
+                />
+                <select
+                    aria-label="Sort by"
+                    class=filter_input_class
+                    on:change=move |ev| set_sort_order.set(SortOrder::from_value(&event_target_value(&ev)))
+                >
+                    {SortOrder::ALL.into_iter().map(|order| view! {
+                        <option value=order.value()>{order.label()}</option>
+                    }).collect_view()}
+                </select>
+            </div>
+
+            <div class="flex min-h-9 items-center gap-3 text-sm">
+                <label class="inline-flex items-center gap-2 cursor-pointer">
+                    <input
+                        type="checkbox"
+                        class="size-4 accent-primary"
+                        prop:checked=move || all_visible_selected.get()
+                        on:change=move |_| toggle_all_visible()
+                    />
+                    "Select all"
+                </label>
+                <Show when=move || !selected.with(HashSet::is_empty)>
+                    <span class="text-muted-foreground">{move || format!("{} selected", selected.with(HashSet::len))}</span>
+                    <Button
+                        variant=ButtonVariant::Destructive
+                        size=ButtonSize::Sm
+                        on_click=move || set_confirm_open.set(true)
+                    >
+                        "Delete selected"
+                    </Button>
+                    <Button variant=ButtonVariant::Ghost size=ButtonSize::Sm on_click=move || set_selected.set(HashSet::new())>
+                        "Clear selection"
+                    </Button>
+                </Show>
+            </div>
+
+            <Transition fallback=|| view! { <SkeletonGrid count=8 label="Loading products..." class="gap-4" /> }>
+                {move || Suspend::new(async move {
+                    Ok::<_, LoadError>(match products.await {
+                        Err(err_msg) => return Err(LoadError::new("products", err_msg)),
+                        Ok(_) if visible_products.with(Vec::is_empty) => view! {
+                            <p class="py-12 text-center text-sm text-muted-foreground">"No products match the current filters."</p>
+                        }.into_any(),
+
+                        Ok(_) => match view_mode.get() {
+                            ViewMode::Grid => view! {
+                                <div class="grid gap-4 sm:grid-cols-2 lg:grid-cols-3 xl:grid-cols-4">
+                                    <For each=move || visible_products.get() key=|product| product.id let:product>
+                                        {
+                                            let id = product.id;
+                                            view! {
+                                                <article class="relative flex flex-col rounded-lg border bg-card p-4 shadow-xs has-[:checked]:ring-2 has-[:checked]:ring-primary">
+                                                    <input
+                                                        type="checkbox"
+                                                        aria-label=format!("Select {}", product.title)
+                                                        class="absolute left-3 top-3 size-4 accent-primary"
+                                                        prop:checked=move || selected.with(|selected| selected.contains(&id))
+                                                        on:change=move |_| toggle_selected(id)
+                                                    />
+                                                    <img src=product.image.clone() alt=product.title.clone() loading="lazy" class="mx-auto h-40 w-full object-contain" />
+                                                    <p class="mt-4 text-xs uppercase tracking-wide text-muted-foreground">{product.category.clone()}</p>
+                                                    <A href=AppRoute::ProductDetail { id }.href() attr:class="mt-1 line-clamp-2 font-medium hover:underline">{product.title.clone()}</A>
+                                                    <p class="mt-auto pt-2 text-lg font-semibold">{format_price(product.price)}</p>
+                                                </article>
+                                            }
+                                        }
+                                    </For>
+                                </div>
+                            }.into_any(),
+                            ViewMode::Table => view! {
+                                <div class="overflow-x-auto rounded-md border">
+                                    <table class="w-full text-sm">
+                                        <thead class="bg-muted/50 text-muted-foreground">
+                                            <tr>
+                                                <th scope="col" class="w-10 px-4 py-3"><span class="sr-only">"Selected"</span></th>
+                                                <th scope="col" class="px-4 py-3 text-left font-medium">"Product"</th>
+                                                <th scope="col" class="px-4 py-3 text-left font-medium">"Category"</th>
+                                                <th scope="col" class="px-4 py-3 text-right font-medium">"Price"</th>
+                                                <th scope="col" class="px-4 py-3 text-right font-medium">"Actions"</th>
+                                            </tr>
+                                        </thead>
+                                        <tbody>
+                                            <For each=move || visible_products.get() key=|product| product.id let:product>
+                                                {
+                                                    let id = product.id;
+                                                    view! {
+                                                        <tr class="border-t hover:bg-muted/30">
+                                                            <td class="px-4 py-2">
+                                                                <input
+                                                                    type="checkbox"
+                                                                    aria-label=format!("Select {}", product.title)
+                                                                    class="size-4 accent-primary"
+                                                                    prop:checked=move || selected.with(|selected| selected.contains(&id))
+                                                                    on:change=move |_| toggle_selected(id)
+                                                                />
+                                                            </td>
+                                                            <td class="px-4 py-2">
+                                                                <div class="flex items-center gap-3">
+                                                                    <img src=product.image.clone() alt="" loading="lazy" class="size-8 object-contain" />
+                                                                    <span class="line-clamp-1 font-medium">{product.title.clone()}</span>
+                                                                </div>
+                                                            </td>
+                                                            <td class="px-4 py-2 text-muted-foreground">{product.category.clone()}</td>
+                                                            <td class="px-4 py-2 text-right tabular-nums">{format_price(product.price)}</td>
+                                                            <td class="px-4 py-2">
+                                                                <div class="flex justify-end gap-1">
+                                                                    <A href=AppRoute::ProductDetail { id }.href() attr:class="inline-flex h-8 items-center rounded-md px-3 hover:bg-accent">"View"</A>
+                                                                    <A href=AppRoute::EditProduct { id }.href() attr:class="inline-flex h-8 items-center rounded-md px-3 hover:bg-accent">"Edit"</A>
+                                                                </div>
+                                                            </td>
+                                                        </tr>
+                                                    }
+                                                }
+                                            </For>
+                                        </tbody>
+                                    </table>
+                                </div>
+                            }.into_any(),
+                        },
+                    })
+                })}
+            </Transition>
+
+            <ConfirmDialog
+                open=confirm_open
+                title=Signal::derive(move || format!("Delete {} product(s)?", selected.with(HashSet::len)))
+                description="The selected products will be removed from the catalogue. This cannot be undone.".to_string()
+                pending=bulk_delete.pending()
+                on_confirm=move || {
+                    bulk_delete.dispatch(selected.get_untracked().into_iter().collect());
+                }
+                on_cancel=move || set_confirm_open.set(false)
+            />
+        </section>
+    }
+}
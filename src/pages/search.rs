@@ -0,0 +1,102 @@
+use crate::api::product_api::get_all_products;
+use crate::components::base::button::{Button, ButtonSize};
+use crate::components::ui::{add_to_cart_button::AddToCartButton, route_error_boundary::LoadError, skeleton::SkeletonTable};
+use crate::components::ui::product_search::{Highlight, search_href, search_products};
+use crate::routes::AppRoute;
+use crate::utils::currency::format_price;
+use leptos::{ev::SubmitEvent, prelude::*};
+use leptos_router::components::A;
+use leptos_router::hooks::{use_navigate, use_query_map};
+
+/// Full results for a storefront search at `/search?q=`.
+#[component]
+pub fn Search() -> impl IntoView {
+    let query_map = use_query_map();
+    let navigate = use_navigate();
+    let query = Memo::new(move |_| query_map.read().get("q").unwrap_or_default());
+
+    let products = LocalResource::new(|| async { get_all_products().await.map_err(|e| e.to_string()) });
+    let (input, set_input) = signal(query.get_untracked());
+
+    // Navbar searches land here while the page is already open
+    Effect::new(move |_| set_input.set(query.get()));
+
+    let results = Memo::new(move |_| {
+        products
+            .get()
+            .and_then(Result::ok)
+            .map(|products| search_products(&products, &query.get()))
+            .unwrap_or_default()
+    });
+
+    let handle_submit = move |ev: SubmitEvent| {
+        ev.prevent_default();
+        navigate(&search_href(&input.get_untracked()), Default::default());
+    };
+
+    view! {
+        <section class="mx-auto max-w-6xl space-y-6 px-6 py-10">
+            <form role="search" class="flex max-w-xl gap-2" on:submit=handle_submit>
+                <input
+                    type="search"
+                    aria-label="Search products"
+                    placeholder="Search products"
+                    class="flex-1 rounded-md border bg-background px-3 py-2 text-sm outline-none focus:ring-2 focus:ring-ring/50"
+                    prop:value=move || input.get()
+                    on:input=move |ev| set_input.set(event_target_value(&ev))
+                />
+                <Button attr:type="submit">"Search"</Button>
+            </form>
+
+            <Transition fallback=|| view! { <SkeletonTable rows=4 columns=3 label="Searching..." /> }>
+                {move || Suspend::new(async move {
+                    Ok::<_, LoadError>(match products.await {
+                        Err(err_msg) => return Err(LoadError::new("products", err_msg)),
+                        Ok(_) if query.with(|query| query.trim().is_empty()) => view! {
+                            <p class="text-muted-foreground">"Type something to search the catalogue."</p>
+                        }.into_any(),
+
+                        Ok(_) => {
+                            let query = query.get();
+                            let list = results.get();
+                            view! {
+                                <h1 class="text-2xl font-semibold" aria-live="polite">
+                                    {format!("{} result(s) for \u{201c}{}\u{201d}", list.len(), query.trim())}
+                                </h1>
+                                {if list.is_empty() {
+                                    view! {
+                                        <p class="text-muted-foreground">
+                                            "No products matched. Try a different word or "
+                                            <A href=AppRoute::Home.href() attr:class="underline">"browse all products"</A>
+                                            "."
+                                        </p>
+                                    }.into_any()
+                                } else {
+                                    view! {
+                                        <ul class="divide-y rounded-lg border">
+                                            {list.into_iter().map(|product| view! {
+                                                <li class="flex items-center gap-4 p-4">
+                                                    <img src=product.image.clone() alt="" class="size-16 shrink-0 rounded bg-white object-contain" />
+                                                    <div class="min-w-0 flex-1">
+                                                        <A href=AppRoute::ShopProduct { id: product.id }.href() attr:class="font-medium hover:underline">
+                                                            <Highlight text=product.title.clone() query=query.clone() />
+                                                        </A>
+                                                        <p class="text-sm capitalize text-muted-foreground">
+                                                            <Highlight text=product.category.clone() query=query.clone() />
+                                                        </p>
+                                                    </div>
+                                                    <span class="font-semibold tabular-nums">{format_price(product.price)}</span>
+                                                    <AddToCartButton product_id=product.id title=product.title size=ButtonSize::Sm />
+                                                </li>
+                                            }).collect_view()}
+                                        </ul>
+                                    }.into_any()
+                                }}
+                            }.into_any()
+                        }
+                    })
+                })}
+            </Transition>
+        </section>
+    }
+}
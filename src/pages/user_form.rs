@@ -0,0 +1,236 @@
+use crate::api::users_api::{User, add_a_user, get_a_user, update_a_user};
+use crate::components::base::button::{Button, ButtonVariant};
+use crate::components::base::icons::*;
+use crate::components::ui::toast::use_toaster;
+use crate::routes::AppRoute;
+use crate::utils::unsaved_changes::use_unsaved_changes_prompt;
+use crate::utils::validation::is_valid_email;
+use leptos::{ev::SubmitEvent, prelude::*};
+use leptos_router::components::A;
+use leptos_router::hooks::{use_navigate, use_params_map};
+
+const MIN_USERNAME_LENGTH: usize = 3;
+const MIN_PASSWORD_LENGTH: usize = 6;
+
+#[derive(Default, Debug, Clone, PartialEq)]
+struct FormData {
+    username: String,
+    email: String,
+    password: String,
+}
+
+impl From<User> for FormData {
+    fn from(user: User) -> Self {
+        Self {
+            username: user.username,
+            email: user.email,
+            password: user.password,
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq)]
+struct FieldErrors {
+    username: Option<&'static str>,
+    email: Option<&'static str>,
+    password: Option<&'static str>,
+}
+
+impl FieldErrors {
+    fn is_empty(&self) -> bool {
+        self.username.is_none() && self.email.is_none() && self.password.is_none()
+    }
+}
+
+fn validate(data: &FormData) -> FieldErrors {
+    let username = if data.username.trim().is_empty() {
+        Some("Username is required.")
+    } else if data.username.trim().chars().count() < MIN_USERNAME_LENGTH {
+        Some("Username must be at least 3 characters.")
+    } else {
+        None
+    };
+
+    let email = if data.email.trim().is_empty() {
+        Some("Email is required.")
+    } else if !is_valid_email(data.email.trim()) {
+        Some("Enter a valid email address.")
+    } else {
+        None
+    };
+
+    let password = if data.password.is_empty() {
+        Some("Password is required.")
+    } else if data.password.chars().count() < MIN_PASSWORD_LENGTH {
+        Some("Password must be at least 6 characters.")
+    } else {
+        None
+    };
+
+    FieldErrors { username, email, password }
+}
+
+/// Create form at `/users/new`, edit form at `/users/:id/edit`.
+#[component]
+pub fn UserForm() -> impl IntoView {
+    let params = use_params_map();
+    let navigate = use_navigate();
+    let toaster = use_toaster();
+
+    let user_id = Memo::new(move |_| params.read().get("id").and_then(|id| id.parse::<u32>().ok()));
+    let is_edit = move || params.read().get("id").is_some();
+
+    let user = LocalResource::new(move || {
+        let user_id = user_id.get();
+        async move {
+            match user_id {
+                Some(user_id) => get_a_user(user_id).await.map(Some).map_err(|e| e.to_string()),
+                None => Ok(None),
+            }
+        }
+    });
+
+    let (form_data, set_form_data) = signal(FormData::default());
+    // Last saved (or loaded) values, the form is dirty while it differs
+    let (initial, set_initial) = signal(FormData::default());
+    let (submitted, set_submitted) = signal(false);
+    let (submit_error, set_submit_error) = signal(None::<String>);
+
+    Effect::new(move |_| {
+        if let Some(Ok(Some(user))) = user.get() {
+            let data = FormData::from(user);
+            set_initial.set(data.clone());
+            set_form_data.set(data);
+        }
+    });
+
+    let field_errors = Memo::new(move |_| validate(&form_data.get()));
+    let dirty = Signal::derive(move || form_data.get() != initial.get());
+    use_unsaved_changes_prompt(dirty);
+
+    let save_action = Action::new_local(move |data: &FormData| {
+        // Fields the form does not edit (name, address, phone) are sent back unchanged
+        let loaded = user.get_untracked().and_then(Result::ok).flatten().unwrap_or_default();
+        let user = User {
+            id: user_id.get_untracked().unwrap_or_default(),
+            username: data.username.trim().to_string(),
+            email: data.email.trim().to_string(),
+            password: data.password.clone(),
+            ..loaded
+        };
+        async move {
+            if user_id.get_untracked().is_some() {
+                update_a_user(user).await
+            } else {
+                add_a_user(user).await
+            }
+            .map_err(|e| e.to_string())
+        }
+    });
+
+    Effect::new(move |_| match save_action.value().get() {
+        Some(Ok(saved)) => {
+            set_initial.set(form_data.get_untracked());
+            toaster.success(format!("User {} saved.", saved.username));
+            navigate(&AppRoute::Users.href(), Default::default());
+        }
+        Some(Err(err)) => set_submit_error.set(Some(err)),
+        None => {}
+    });
+
+    let handle_form_submit = move |ev: SubmitEvent| {
+        ev.prevent_default();
+        set_submitted.set(true);
+        set_submit_error.set(None);
+        if field_errors.get_untracked().is_empty() && !save_action.pending().get_untracked() {
+            save_action.dispatch(form_data.get_untracked());
+        }
+    };
+
+    let field = move |label: &'static str,
+                      name: &'static str,
+                      input_type: &'static str,
+                      autocomplete: &'static str,
+                      value: fn(&FormData) -> String,
+                      set: fn(&mut FormData, String),
+                      error: fn(&FieldErrors) -> Option<&'static str>| {
+        let error_id = format!("{name}-error");
+        let show_error = move || submitted.get().then(|| error(&field_errors.get())).flatten();
+        view! {
+            <div>
+                <label for=name class="text-sm font-medium">{label}</label>
+                <input
+                    id=name
+                    name=name
+                    type=input_type
+                    autocomplete=autocomplete
+                    aria-describedby=error_id.clone()
+                    aria-invalid=move || show_error().is_some().to_string()
+                    class="mt-1 w-full rounded-md border bg-background px-3 py-2 text-sm outline-none focus:ring-2 focus:ring-ring/50 aria-invalid:border-destructive"
+                    prop:value=move || value(&form_data.get())
+                    on:input=move |ev| set_form_data.update(|data| set(data, event_target_value(&ev)))
+                />
+                <p id=error_id class="mt-1 text-xs text-destructive">{show_error}</p>
+            </div>
+        }
+    };
+
+    view! {
+        <section class="p-6 max-w-xl space-y-6">
+            <div>
+                <A href=AppRoute::Users.href() attr:class="text-sm text-muted-foreground hover:text-foreground">"← Back to users"</A>
+                <h1 class="mt-2 text-2xl font-semibold">
+                    {move || if is_edit() { "Edit user" } else { "New user" }}
+                </h1>
+            </div>
+
+            {move || match user.get() {
+                None if is_edit() => view! {
+                    <div class="flex items-center gap-2 text-muted-foreground" role="status">
+                        <IconView icon=Icon::LOADER_CIRCLE.clone() class="animate-spin" />
+                        "Loading user..."
+                    </div>
+                }.into_any(),
+                Some(Err(err_msg)) => view! {
+                    <p class="rounded-md border border-destructive/40 bg-destructive/5 p-4 text-sm text-destructive" role="alert">
+                        "Could not load user: " {err_msg}
+                    </p>
+                }.into_any(),
+                Some(Ok(None)) if is_edit() => view! {
+                    <p class="text-sm text-muted-foreground">"This user does not exist."</p>
+                }.into_any(),
+                _ => view! {
+                    <form class="space-y-4" on:submit=handle_form_submit novalidate>
+                        {field("Username", "username", "text", "username", |d| d.username.clone(), |d, v| d.username = v, |e| e.username)}
+                        {field("Email", "email", "email", "email", |d| d.email.clone(), |d, v| d.email = v, |e| e.email)}
+                        {field("Password", "password", "password", "new-password", |d| d.password.clone(), |d, v| d.password = v, |e| e.password)}
+
+                        {move || submit_error.get().map(|err| view! {
+                            <p class="text-sm text-destructive" role="alert">"Saving failed: " {err}</p>
+                        })}
+
+                        <div class="flex items-center gap-2 pt-2">
+                            <Button attr:type="submit" attr:disabled=move || save_action.pending().get()>
+                                {move || save_action.pending().get().then(|| view! {
+                                    <IconView icon=Icon::LOADER_CIRCLE.clone() class="animate-spin" />
+                                })}
+                                {move || if is_edit() { "Save changes" } else { "Create user" }}
+                            </Button>
+                            <Button
+                                variant=ButtonVariant::Ghost
+                                attr:type="button"
+                                attr:disabled=move || !dirty.get()
+                                on_click=move || set_form_data.set(initial.get_untracked())
+                            >
+                                "Reset"
+                            </Button>
+                            <span class="text-xs text-muted-foreground" class:hidden=move || !dirty.get()>
+                                "Unsaved changes"
+                            </span>
+                        </div>
+                    </form>
+                }.into_any(),
+            }}
+        </section>
+    }
+}
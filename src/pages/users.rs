@@ -0,0 +1,281 @@
+use crate::api::users_api::{User, delete_a_user, get_all_users};
+use crate::components::base::button::{Button, ButtonSize, ButtonVariant};
+use crate::components::base::icons::*;
+use crate::components::ui::{
+    confirm_dialog::ConfirmDialog,
+    pagination::Pagination,
+    route_error_boundary::LoadError,
+    skeleton::SkeletonTable,
+};
+use crate::routes::AppRoute;
+use leptos::prelude::*;
+use leptos_router::components::A;
+use std::collections::HashSet;
+
+const PAGE_SIZE: usize = 10;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum SortKey {
+    Id,
+    Username,
+    Email,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Sort {
+    key: SortKey,
+    ascending: bool,
+}
+
+fn sort_users(users: &mut [User], sort: Sort) {
+    users.sort_by(|a, b| {
+        let ordering = match sort.key {
+            SortKey::Id => a.id.cmp(&b.id),
+            SortKey::Username => a.username.to_lowercase().cmp(&b.username.to_lowercase()),
+            SortKey::Email => a.email.to_lowercase().cmp(&b.email.to_lowercase()),
+        };
+        if sort.ascending { ordering } else { ordering.reverse() }
+    });
+}
+
+fn matches_query(user: &User, query: &str) -> bool {
+    query.is_empty()
+        || user.username.to_lowercase().contains(query)
+        || user.email.to_lowercase().contains(query)
+        || user.id.to_string() == query
+}
+
+#[component]
+pub fn Users() -> impl IntoView {
+    let users = LocalResource::new(|| async { get_all_users().await.map_err(|e| e.to_string()) });
+
+    let (query, set_query) = signal(String::new());
+    let (sort, set_sort) = signal(Sort { key: SortKey::Id, ascending: true });
+    let page = RwSignal::new(0usize);
+    // FakeStore does not persist deletes, so removed rows are hidden locally
+    let (removed, set_removed) = signal(HashSet::<u32>::new());
+    let (viewing, set_viewing) = signal(None::<User>);
+    let (pending_delete, set_pending_delete) = signal(None::<User>);
+    let (delete_error, set_delete_error) = signal(None::<String>);
+
+    let delete_action = Action::new_local(|user_id: &u32| {
+        let user_id = *user_id;
+        async move { delete_a_user(user_id).await.map(|_| user_id).map_err(|e| e.to_string()) }
+    });
+
+    Effect::new(move |_| {
+        match delete_action.value().get() {
+            Some(Ok(user_id)) => {
+                set_removed.update(|removed| {
+                    removed.insert(user_id);
+                });
+                set_pending_delete.set(None);
+            }
+            Some(Err(err)) => set_delete_error.set(Some(err)),
+            None => {}
+        }
+    });
+
+    let visible_users = Memo::new(move |_| {
+        let query = query.get().trim().to_lowercase();
+        let removed = removed.get();
+        let mut list: Vec<User> = users
+            .get()
+            .and_then(Result::ok)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|user| !removed.contains(&user.id) && matches_query(user, &query))
+            .collect();
+        sort_users(&mut list, sort.get());
+        list
+    });
+    let total = Signal::derive(move || visible_users.with(Vec::len));
+
+    let sort_by = move |key: SortKey| {
+        set_sort.update(|sort| {
+            if sort.key == key {
+                sort.ascending = !sort.ascending;
+            } else {
+                *sort = Sort { key, ascending: true };
+            }
+        });
+    };
+
+    let header = move |label: &'static str, key: SortKey| {
+        view! {
+            <th
+                scope="col"
+                class="px-4 py-3 text-left font-medium"
+                aria-sort=move || {
+                    let sort = sort.get();
+                    match (sort.key == key, sort.ascending) {
+                        (false, _) => "none",
+                        (true, true) => "ascending",
+                        (true, false) => "descending",
+                    }
+                }
+            >
+                <button class="inline-flex items-center gap-1 cursor-pointer hover:text-foreground" on:click=move |_| sort_by(key)>
+                    {label}
+                    <span class="text-xs" aria-hidden="true">
+                        {move || {
+                            let sort = sort.get();
+                            match (sort.key == key, sort.ascending) {
+                                (false, _) => "↕",
+                                (true, true) => "↑",
+                                (true, false) => "↓",
+                            }
+                        }}
+                    </span>
+                </button>
+            </th>
+        }
+    };
+
+    view! {
+        <section class="p-6 space-y-4">
+            <div class="flex flex-wrap items-center justify-between gap-4">
+                <div>
+                    <h1 class="text-2xl font-semibold">"Users"</h1>
+                    <p class="text-sm text-muted-foreground">"Manage the accounts registered in the store."</p>
+                </div>
+                <A href=AppRoute::NewUser.href() attr:class="inline-flex items-center gap-2 rounded-md bg-primary px-4 py-2 text-sm font-medium text-primary-foreground hover:bg-primary/90">
+                    <IconView icon=Icon::USER.clone() />
+                    "New user"
+                </A>
+            </div>
+
+            <input
+                type="search"
+                placeholder="Search by username or email"
+                aria-label="Search users"
+                class="w-full max-w-sm rounded-md border bg-background px-3 py-2 text-sm outline-none focus:ring-2 focus:ring-ring/50"
+                prop:value=move || query.get()
+                on:input=move |ev| {
+                    set_query.set(event_target_value(&ev));
+                    page.set(0);
+                }
+            />
+
+            <Transition fallback=|| view! { <SkeletonTable rows=PAGE_SIZE columns=4 label="Loading users..." /> }>
+                {move || Suspend::new(async move {
+                    Ok::<_, LoadError>(match users.await {
+                        Err(err_msg) => return Err(LoadError::new("users", err_msg)),
+                        Ok(_) => view! {
+                            <div class="overflow-x-auto rounded-md border">
+                                <table class="w-full text-sm">
+                                    <thead class="bg-muted/50 text-muted-foreground">
+                                        <tr>
+                                            {header("ID", SortKey::Id)}
+                                            {header("Username", SortKey::Username)}
+                                            {header("Email", SortKey::Email)}
+                                            <th scope="col" class="px-4 py-3 text-right font-medium">"Actions"</th>
+                                        </tr>
+                                    </thead>
+                                    <tbody>
+                                        <Show
+                                            when=move || total.get() > 0
+                                            fallback=|| view! {
+                                                <tr>
+                                                    <td colspan="4" class="px-4 py-12 text-center text-muted-foreground">"No users found."</td>
+                                                </tr>
+                                            }
+                                        >
+                                            {move || {
+                                                let start = page.get() * PAGE_SIZE;
+                                                visible_users.get().into_iter().skip(start).take(PAGE_SIZE).map(|user| {
+                                                    let user_id = user.id;
+                                                    let view_user = user.clone();
+                                                    let delete_user = user.clone();
+                                                    view! {
+                                                        <tr class="border-t hover:bg-muted/30">
+                                                            <td class="px-4 py-3 tabular-nums">{user.id}</td>
+                                                            <td class="px-4 py-3 font-medium">{user.username}</td>
+                                                            <td class="px-4 py-3 text-muted-foreground">{user.email}</td>
+                                                            <td class="px-4 py-3">
+                                                                <div class="flex justify-end gap-1">
+                                                                    <Button
+                                                                        variant=ButtonVariant::Ghost
+                                                                        size=ButtonSize::Sm
+                                                                        on_click=move || set_viewing.set(Some(view_user.clone()))
+                                                                    >
+                                                                        "View"
+                                                                    </Button>
+                                                                    <A href=AppRoute::EditUser { id: user_id }.href() attr:class="inline-flex h-8 items-center rounded-md px-3 text-sm font-medium hover:bg-accent">
+                                                                        "Edit"
+                                                                    </A>
+                                                                    <Button
+                                                                        variant=ButtonVariant::Ghost
+                                                                        size=ButtonSize::Sm
+                                                                        class="text-destructive".to_string()
+                                                                        on_click=move || {
+                                                                            set_delete_error.set(None);
+                                                                            set_pending_delete.set(Some(delete_user.clone()));
+                                                                        }
+                                                                    >
+                                                                        "Delete"
+                                                                    </Button>
+                                                                </div>
+                                                            </td>
+                                                        </tr>
+                                                    }
+                                                }).collect_view()
+                                            }}
+                                        </Show>
+                                    </tbody>
+                                </table>
+                            </div>
+                            <Pagination page=page total=total page_size=PAGE_SIZE />
+                        }.into_any(),
+                    })
+                })}
+            </Transition>
+
+            <Show when=move || viewing.get().is_some()>
+                {move || viewing.get().map(|user| view! {
+                    <div class="fixed inset-0 z-40 flex justify-end bg-black/30 animate-fade-in" on:click=move |_| set_viewing.set(None)>
+                        <aside
+                            role="dialog"
+                            aria-modal="true"
+                            aria-label="User details"
+                            class="h-full w-full max-w-sm bg-background p-6 shadow-xl animate-slide-in-right"
+                            on:click=|ev| ev.stop_propagation()
+                        >
+                            <div class="flex items-center justify-between">
+                                <h2 class="text-lg font-semibold">{user.username.clone()}</h2>
+                                <Button variant=ButtonVariant::Ghost size=ButtonSize::Sm on_click=move || set_viewing.set(None)>"Close"</Button>
+                            </div>
+                            <dl class="mt-6 space-y-3 text-sm">
+                                <div><dt class="text-muted-foreground">"ID"</dt><dd class="font-medium">{user.id}</dd></div>
+                                <div><dt class="text-muted-foreground">"Username"</dt><dd class="font-medium">{user.username.clone()}</dd></div>
+                                <div><dt class="text-muted-foreground">"Email"</dt><dd class="font-medium">{user.email.clone()}</dd></div>
+                            </dl>
+                            <A href=AppRoute::EditUser { id: user.id }.href() attr:class="mt-6 inline-flex rounded-md bg-primary px-4 py-2 text-sm text-primary-foreground">
+                                "Edit user"
+                            </A>
+                        </aside>
+                    </div>
+                })}
+            </Show>
+
+            <ConfirmDialog
+                open=Signal::derive(move || pending_delete.get().is_some())
+                title="Delete user?".to_string()
+                description=Signal::derive(move || {
+                    let name = pending_delete.get().map(|user| user.username).unwrap_or_default();
+                    match delete_error.get() {
+                        Some(err) => format!("Deleting {name} failed: {err}"),
+                        None => format!("{name} will be removed permanently. This cannot be undone."),
+                    }
+                })
+                pending=delete_action.pending()
+                on_confirm=move || {
+                    if let Some(user) = pending_delete.get_untracked() {
+                        delete_action.dispatch(user.id);
+                    }
+                }
+                on_cancel=move || set_pending_delete.set(None)
+            />
+        </section>
+    }
+}
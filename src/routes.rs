@@ -0,0 +1,464 @@
+use leptos::prelude::*;
+use leptos_router::components::{ParentRoute, Route, Routes};
+use leptos_router::hooks::use_location;
+use leptos_router::path;
+
+use crate::components::base::icons::Icon;
+use crate::components::ui::sidebar_navigation::MatchMode;
+use crate::layouts::{private_layout::PrivateLayout, public_layout::PublicLayout};
+use crate::pages::{
+    about::About,
+    cart_detail::CartDetail,
+    carts::Carts,
+    checkout::Checkout,
+    contact::Contact,
+    dashboard::Dashboard,
+    home::Home,
+    login::Login,
+    not_found::NotFound,
+    privacy::Privacy,
+    product_detail::{ProductDetailPage, ShopProductPage},
+    product_form::ProductForm,
+    products::Products,
+    search::Search,
+    user_form::UserForm,
+    users::Users,
+};
+use crate::session::context::MANAGE_USERS;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Layout {
+    Public,
+    Private,
+}
+
+/// Collapsible sidebar entry that groups several routes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SidebarGroup {
+    Products,
+}
+
+impl SidebarGroup {
+    pub fn title(&self) -> &'static str {
+        match self {
+            SidebarGroup::Products => "Products",
+        }
+    }
+
+    pub fn icon(&self) -> Icon {
+        match self {
+            SidebarGroup::Products => Icon::PRODUCTS,
+        }
+    }
+}
+
+/// Where a route is linked from. Only routes without parameters can be placed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NavPlacement {
+    /// Top level sidebar link
+    Sidebar(MatchMode),
+    /// Link inside a collapsible sidebar group
+    SidebarGroup(SidebarGroup, MatchMode),
+    /// Storefront navbar link
+    Navbar,
+}
+
+/// Declares every route once. Generates the `AppRoute` enum with its
+/// metadata and link helpers, and the `AppRoutes` tree mounted by `App`.
+macro_rules! route_table {
+    (
+        $(
+            $layout:ident($layout_view:ident) {
+                $(
+                    $variant:ident $({ $($param:ident),+ })? => {
+                        path: $path:tt,
+                        view: $view:ident,
+                        title: $title:literal,
+                        icon: $icon:expr,
+                        permission: $permission:expr,
+                        nav: [$($nav:expr),* $(,)?],
+                        parent: $parent:expr $(,)?
+                    }
+                ),* $(,)?
+            }
+        )*
+    ) => {
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum AppRoute {
+            $($(
+                $variant $({ $($param: u32),+ })?,
+            )*)*
+        }
+
+        const PATTERNS: &[&str] = &[$($($path,)*)*];
+
+        impl AppRoute {
+            /// Router pattern, with `:param` placeholders.
+            pub fn pattern(&self) -> &'static str {
+                match self {
+                    $($(AppRoute::$variant { .. } => $path,)*)*
+                }
+            }
+
+            /// Concrete url of this route, parameters filled in.
+            pub fn href(&self) -> String {
+                match *self {
+                    $($(
+                        AppRoute::$variant { $($($param,)+)? .. } => {
+                            fill_pattern($path, &[$($((stringify!($param), $param)),+)?])
+                        }
+                    )*)*
+                }
+            }
+
+            pub fn title(&self) -> &'static str {
+                match self {
+                    $($(AppRoute::$variant { .. } => $title,)*)*
+                }
+            }
+
+            pub fn icon(&self) -> Option<Icon> {
+                match self {
+                    $($(AppRoute::$variant { .. } => $icon,)*)*
+                }
+            }
+
+            /// Permission the user needs to see the route in navigation.
+            pub fn permission(&self) -> Option<&'static str> {
+                match self {
+                    $($(AppRoute::$variant { .. } => $permission,)*)*
+                }
+            }
+
+            pub fn layout(&self) -> Layout {
+                match self {
+                    $($(AppRoute::$variant { .. } => Layout::$layout,)*)*
+                }
+            }
+
+            pub fn nav(&self) -> &'static [NavPlacement] {
+                match self {
+                    $($(AppRoute::$variant { .. } => &[$($nav),*],)*)*
+                }
+            }
+
+            /// Route one level up in the breadcrumb trail.
+            #[allow(unused_variables)]
+            pub fn parent(&self) -> Option<AppRoute> {
+                match *self {
+                    $($(AppRoute::$variant { $($($param,)+)? .. } => $parent,)*)*
+                }
+            }
+
+            /// Route matching a location pathname, query and hash excluded.
+            #[allow(unused_variables)]
+            pub fn from_path(path: &str) -> Option<AppRoute> {
+                $($(
+                    if let Some(params) = match_pattern($path, path) {
+                        return Some(AppRoute::$variant {
+                            $($($param: param_value(&params, stringify!($param))?,)+)?
+                        });
+                    }
+                )*)*
+                None
+            }
+        }
+
+        #[component]
+        pub fn AppRoutes() -> impl IntoView {
+            view! {
+                <Routes fallback=NotFound>
+                    $(
+                        <ParentRoute path=path!("") view=$layout_view>
+                            $(<Route path=path!($path) view=$view />)*
+                        </ParentRoute>
+                    )*
+                </Routes>
+            }
+        }
+    };
+}
+
+route_table! {
+    Public(PublicLayout) {
+        Home => {
+            path: "/",
+            view: Home,
+            title: "Home",
+            icon: Some(Icon::HOME),
+            permission: None,
+            nav: [NavPlacement::Sidebar(MatchMode::Exact), NavPlacement::Navbar],
+            parent: None,
+        },
+        Login => {
+            path: "/login",
+            view: Login,
+            title: "Login",
+            icon: None,
+            permission: None,
+            nav: [],
+            parent: None,
+        },
+        Search => {
+            path: "/search",
+            view: Search,
+            title: "Search",
+            icon: None,
+            permission: None,
+            nav: [],
+            parent: Some(AppRoute::Home),
+        },
+        Checkout => {
+            path: "/checkout",
+            view: Checkout,
+            title: "Checkout",
+            icon: None,
+            permission: None,
+            nav: [],
+            parent: Some(AppRoute::Home),
+        },
+        ShopProduct { id } => {
+            path: "/shop/:id",
+            view: ShopProductPage,
+            title: "Product",
+            icon: None,
+            permission: None,
+            nav: [],
+            parent: Some(AppRoute::Home),
+        },
+        About => {
+            path: "/about",
+            view: About,
+            title: "About",
+            icon: None,
+            permission: None,
+            nav: [NavPlacement::Navbar],
+            parent: Some(AppRoute::Home),
+        },
+        Contact => {
+            path: "/contact",
+            view: Contact,
+            title: "Contact",
+            icon: None,
+            permission: None,
+            nav: [NavPlacement::Navbar],
+            parent: Some(AppRoute::Home),
+        },
+        Privacy => {
+            path: "/privacy",
+            view: Privacy,
+            title: "Privacy Policy",
+            icon: None,
+            permission: None,
+            nav: [],
+            parent: Some(AppRoute::Home),
+        },
+    }
+
+    Private(PrivateLayout) {
+        Dashboard => {
+            path: "/dashboard",
+            view: Dashboard,
+            title: "Dashboard",
+            icon: Some(Icon::BAR_CHART),
+            permission: None,
+            nav: [NavPlacement::Sidebar(MatchMode::Prefix)],
+            parent: None,
+        },
+        Users => {
+            path: "/users",
+            view: Users,
+            title: "Users",
+            icon: Some(Icon::USER),
+            permission: Some(MANAGE_USERS),
+            nav: [NavPlacement::Sidebar(MatchMode::Prefix)],
+            parent: Some(AppRoute::Dashboard),
+        },
+        NewUser => {
+            path: "/users/new",
+            view: UserForm,
+            title: "New user",
+            icon: None,
+            permission: Some(MANAGE_USERS),
+            nav: [],
+            parent: Some(AppRoute::Users),
+        },
+        EditUser { id } => {
+            path: "/users/:id/edit",
+            view: UserForm,
+            title: "Edit user",
+            icon: None,
+            permission: Some(MANAGE_USERS),
+            nav: [],
+            parent: Some(AppRoute::Users),
+        },
+        Products => {
+            path: "/products",
+            view: Products,
+            title: "Products",
+            icon: Some(Icon::PRODUCTS),
+            permission: None,
+            nav: [NavPlacement::SidebarGroup(SidebarGroup::Products, MatchMode::Prefix)],
+            parent: Some(AppRoute::Dashboard),
+        },
+        NewProduct => {
+            path: "/products/new",
+            view: ProductForm,
+            title: "New product",
+            icon: None,
+            permission: None,
+            nav: [NavPlacement::SidebarGroup(SidebarGroup::Products, MatchMode::Exact)],
+            parent: Some(AppRoute::Products),
+        },
+        ProductDetail { id } => {
+            path: "/products/:id",
+            view: ProductDetailPage,
+            title: "Product",
+            icon: None,
+            permission: None,
+            nav: [],
+            parent: Some(AppRoute::Products),
+        },
+        EditProduct { id } => {
+            path: "/products/:id/edit",
+            view: ProductForm,
+            title: "Edit product",
+            icon: None,
+            permission: None,
+            nav: [],
+            parent: Some(AppRoute::ProductDetail { id }),
+        },
+        Carts => {
+            path: "/cart",
+            view: Carts,
+            title: "Carts",
+            icon: Some(Icon::CART),
+            permission: None,
+            nav: [NavPlacement::Sidebar(MatchMode::Prefix)],
+            parent: Some(AppRoute::Dashboard),
+        },
+        CartDetail { id } => {
+            path: "/cart/:id",
+            view: CartDetail,
+            title: "Cart",
+            icon: None,
+            permission: None,
+            nav: [],
+            parent: Some(AppRoute::Carts),
+        },
+    }
+}
+
+impl AppRoute {
+    /// Routes without parameters, in table order.
+    pub fn static_routes() -> impl Iterator<Item = AppRoute> {
+        PATTERNS.iter().filter_map(|pattern| AppRoute::from_path(pattern))
+    }
+
+    /// Routes linked from `placement`, in table order.
+    pub fn placed(placement: NavPlacement) -> impl Iterator<Item = AppRoute> {
+        AppRoute::static_routes().filter(move |route| route.nav().contains(&placement))
+    }
+
+    /// Breadcrumb trail from the top level route down to this one.
+    pub fn trail(&self) -> Vec<AppRoute> {
+        let mut trail = vec![*self];
+        while let Some(parent) = trail.last().and_then(|route| route.parent()) {
+            trail.push(parent);
+        }
+        trail.reverse();
+        trail
+    }
+}
+
+/// Route of the current location, `None` when nothing matched.
+pub fn use_current_route() -> Memo<Option<AppRoute>> {
+    let pathname = use_location().pathname;
+    Memo::new(move |_| pathname.with(|path| AppRoute::from_path(path)))
+}
+
+/// Values of the `:param` segments when `path` fits `pattern`.
+fn match_pattern(pattern: &'static str, path: &str) -> Option<Vec<(&'static str, u32)>> {
+    let mut expected = pattern.split('/').filter(|segment| !segment.is_empty());
+    let mut actual = path.split('/').filter(|segment| !segment.is_empty());
+    let mut params = Vec::new();
+    loop {
+        match (expected.next(), actual.next()) {
+            (None, None) => return Some(params),
+            (Some(expected), Some(actual)) => match expected.strip_prefix(':') {
+                Some(name) => params.push((name, actual.parse().ok()?)),
+                None if expected == actual => {}
+                None => return None,
+            },
+            _ => return None,
+        }
+    }
+}
+
+fn param_value(params: &[(&str, u32)], name: &str) -> Option<u32> {
+    params.iter().find(|(param, _)| *param == name).map(|(_, value)| *value)
+}
+
+fn fill_pattern(pattern: &str, params: &[(&str, u32)]) -> String {
+    params.iter().fold(pattern.to_string(), |path, (name, value)| {
+        path.replace(&format!(":{name}"), &value.to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all_routes() -> Vec<AppRoute> {
+        let mut routes: Vec<AppRoute> = AppRoute::static_routes().collect();
+        routes.extend([
+            AppRoute::ShopProduct { id: 3 },
+            AppRoute::EditUser { id: 7 },
+            AppRoute::ProductDetail { id: 12 },
+            AppRoute::EditProduct { id: 12 },
+            AppRoute::CartDetail { id: 5 },
+        ]);
+        routes
+    }
+
+    #[test]
+    fn href_round_trips_through_from_path() {
+        for route in all_routes() {
+            assert_eq!(AppRoute::from_path(&route.href()), Some(route), "{}", route.href());
+        }
+    }
+
+    #[test]
+    fn every_pattern_without_params_is_a_static_route() {
+        let static_count = PATTERNS.iter().filter(|pattern| !pattern.contains(':')).count();
+        assert_eq!(AppRoute::static_routes().count(), static_count);
+    }
+
+    #[test]
+    fn literal_segments_win_over_params() {
+        assert_eq!(AppRoute::from_path("/products/new"), Some(AppRoute::NewProduct));
+        assert_eq!(AppRoute::from_path("/users/new"), Some(AppRoute::NewUser));
+        assert_eq!(AppRoute::from_path("/products/4"), Some(AppRoute::ProductDetail { id: 4 }));
+    }
+
+    #[test]
+    fn non_numeric_params_are_rejected() {
+        assert_eq!(AppRoute::from_path("/products/abc"), None);
+        assert_eq!(AppRoute::from_path("/cart/-1"), None);
+        assert_eq!(AppRoute::from_path("/users/1.5/edit"), None);
+        assert_eq!(match_pattern("/shop/:id", "/shop/x"), None);
+    }
+
+    #[test]
+    fn trailing_and_extra_segments() {
+        assert_eq!(AppRoute::from_path("/users/"), Some(AppRoute::Users));
+        assert_eq!(AppRoute::from_path("/users/1/edit/more"), None);
+        assert_eq!(AppRoute::from_path("/nowhere"), None);
+    }
+
+    #[test]
+    fn fill_pattern_replaces_every_param() {
+        assert_eq!(fill_pattern("/users/:id/edit", &[("id", 9)]), "/users/9/edit");
+        assert_eq!(AppRoute::EditProduct { id: 2 }.href(), "/products/2/edit");
+    }
+}
@@ -0,0 +1,248 @@
+use std::sync::Arc;
+
+use leptos::prelude::*;
+
+use crate::api::{TokenProvider, auth_api::LoginResponse, set_token_provider};
+use crate::session::store::{CookieAttributes, REFRESH_TOKEN_KEY, SessionStore, StoreKind, TOKEN_KEY};
+use crate::session::sync::{SessionEvent, use_session_sync};
+
+// 30 days, in milliseconds
+const REMEMBER_ME_MAX_AGE: i64 = 30 * 24 * 60 * 60 * 1000;
+
+/// Needed for the user management routes.
+pub const MANAGE_USERS: &str = "users:manage";
+
+// Comma separated account ids holding the admin role, FakeStore's `johnd` by default
+fn admin_user_ids() -> impl Iterator<Item = u32> {
+    option_env!("ADMIN_USER_IDS")
+        .unwrap_or("1")
+        .split(',')
+        .filter_map(|id| id.trim().parse().ok())
+}
+
+/// FakeStore tokens carry no roles, so the role follows from the account id.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Role {
+    Admin,
+    Staff,
+}
+
+impl Role {
+    fn of(user_id: Option<u32>) -> Self {
+        match user_id {
+            Some(user_id) if admin_user_ids().any(|id| id == user_id) => Role::Admin,
+            _ => Role::Staff,
+        }
+    }
+
+    pub fn permissions(&self) -> &'static [&'static str] {
+        match self {
+            Role::Admin => &[MANAGE_USERS],
+            Role::Staff => &[],
+        }
+    }
+}
+
+/// Which stores back a "remember me" login and a regular one.
+#[derive(Clone, Debug)]
+pub struct SessionConfig {
+    pub persistent: StoreKind,
+    pub ephemeral: StoreKind,
+}
+
+impl Default for SessionConfig {
+    fn default() -> Self {
+        Self {
+            persistent: StoreKind::Cookie(CookieAttributes {
+                max_age: Some(REMEMBER_ME_MAX_AGE),
+                ..Default::default()
+            }),
+            ephemeral: StoreKind::SessionStorage,
+        }
+    }
+}
+
+// Access and refresh token saved side by side in the same kind of store
+struct TokenStores {
+    access: Arc<dyn SessionStore>,
+    refresh: Arc<dyn SessionStore>,
+}
+
+impl TokenStores {
+    fn new(kind: StoreKind) -> Self {
+        Self {
+            access: kind.clone().build(TOKEN_KEY),
+            refresh: kind.build(REFRESH_TOKEN_KEY),
+        }
+    }
+
+    fn save(&self, token: &str, refresh_token: Option<&str>) {
+        self.access.save(token);
+        match refresh_token {
+            Some(refresh_token) => self.refresh.save(refresh_token),
+            None => self.refresh.clear(),
+        }
+    }
+
+    fn clear(&self) {
+        self.access.clear();
+        self.refresh.clear();
+    }
+}
+
+type Broadcast = Box<dyn Fn(&SessionEvent)>;
+
+#[derive(Copy, Clone)]
+pub struct Session {
+    token: RwSignal<Option<String>>,
+    persistent: StoredValue<TokenStores>,
+    ephemeral: StoredValue<TokenStores>,
+    broadcast: StoredValue<Option<Broadcast>, LocalStorage>,
+}
+
+impl Session {
+    pub fn token(&self) -> Signal<Option<String>> {
+        self.token.into()
+    }
+
+    pub fn is_authenticated(&self) -> bool {
+        self.token.with(Option::is_some)
+    }
+
+    /// Account id from the token's `sub` claim, FakeStore tokens carry it.
+    pub fn user_id(&self) -> Option<u32> {
+        self.token.with_untracked(|token| token.as_deref().and_then(token_subject))
+    }
+
+    /// Role of the signed-in account, `None` when signed out.
+    pub fn role(&self) -> Option<Role> {
+        self.token.with(|token| token.as_deref().map(|token| Role::of(token_subject(token))))
+    }
+
+    pub fn has_permission(&self, permission: &str) -> bool {
+        self.role().is_some_and(|role| role.permissions().contains(&permission))
+    }
+
+    /// Saves the tokens in the persistent store when `remember` is set,
+    /// otherwise in the ephemeral one. The other store is cleared.
+    pub fn sign_in(&self, token: String, refresh_token: Option<String>, remember: bool) {
+        let (keep, discard) = if remember {
+            (self.persistent, self.ephemeral)
+        } else {
+            (self.ephemeral, self.persistent)
+        };
+        discard.with_value(TokenStores::clear);
+        keep.with_value(|stores| stores.save(&token, refresh_token.as_deref()));
+        self.notify(&SessionEvent::LoggedIn(token.clone()));
+        self.token.set(Some(token));
+    }
+
+    /// Replaces the tokens in whichever store currently holds them.
+    pub fn refresh(&self, token: String, refresh_token: Option<String>) {
+        self.active_stores().with_value(|stores| {
+            // Keep the old refresh token when the backend does not rotate it
+            let refresh_token = refresh_token.or_else(|| stores.refresh.load());
+            stores.save(&token, refresh_token.as_deref());
+        });
+        self.notify(&SessionEvent::TokenRefreshed(token.clone()));
+        self.token.set(Some(token));
+    }
+
+    pub fn sign_out(&self) {
+        self.clear_stores();
+        self.notify(&SessionEvent::LoggedOut);
+        self.token.set(None);
+    }
+
+    // Applies an event received from another tab, without re-broadcasting it.
+    // Shared stores (cookie, localStorage) were already written by the sender.
+    fn receive(&self, event: SessionEvent) {
+        match event {
+            SessionEvent::LoggedIn(token) | SessionEvent::TokenRefreshed(token) => {
+                self.token.set(Some(token))
+            }
+            SessionEvent::LoggedOut => {
+                self.clear_stores();
+                self.token.set(None);
+            }
+        }
+    }
+
+    fn active_stores(&self) -> StoredValue<TokenStores> {
+        if self.persistent.with_value(|stores| stores.access.load().is_some()) {
+            self.persistent
+        } else {
+            self.ephemeral
+        }
+    }
+
+    fn clear_stores(&self) {
+        self.persistent.with_value(TokenStores::clear);
+        self.ephemeral.with_value(TokenStores::clear);
+    }
+
+    fn notify(&self, event: &SessionEvent) {
+        self.broadcast.with_value(|broadcast| {
+            if let Some(broadcast) = broadcast {
+                broadcast(event);
+            }
+        });
+    }
+}
+
+impl TokenProvider for Session {
+    fn access_token(&self) -> Option<String> {
+        self.token.get_untracked()
+    }
+
+    fn refresh_token(&self) -> Option<String> {
+        self.active_stores().with_value(|stores| stores.refresh.load())
+    }
+
+    fn on_refreshed(&self, response: LoginResponse) {
+        self.refresh(response.token, response.refresh_token);
+    }
+
+    fn on_refresh_failed(&self) {
+        self.sign_out();
+    }
+}
+
+// Reads `sub` from the JWT payload without verifying the signature,
+// the backend does that on every request.
+fn token_subject(token: &str) -> Option<u32> {
+    let payload = token.split('.').nth(1)?;
+    let mut base64 = payload.replace('-', "+").replace('_', "/");
+    while base64.len() % 4 != 0 {
+        base64.push('=');
+    }
+    let json = window().atob(&base64).ok()?;
+    let claims: serde_json::Value = serde_json::from_str(&json).ok()?;
+    claims.get("sub")?.as_u64().and_then(|id| u32::try_from(id).ok())
+}
+
+/// Builds the configured stores, restores any saved token, starts cross-tab
+/// syncing and provides the `Session` context.
+pub fn provide_session(config: SessionConfig) -> Session {
+    let persistent = TokenStores::new(config.persistent);
+    let ephemeral = TokenStores::new(config.ephemeral);
+    let token = persistent.access.load().or_else(|| ephemeral.access.load());
+
+    let session = Session {
+        token: RwSignal::new(token),
+        persistent: StoredValue::new(persistent),
+        ephemeral: StoredValue::new(ephemeral),
+        broadcast: StoredValue::new_local(None),
+    };
+
+    let broadcast = use_session_sync(move |event| session.receive(event));
+    session.broadcast.set_value(Some(Box::new(broadcast)));
+    set_token_provider(session);
+
+    provide_context(session);
+    session
+}
+
+pub fn use_session() -> Session {
+    expect_context::<Session>()
+}
@@ -0,0 +1,84 @@
+use leptos::prelude::*;
+use leptos_router::{NavigateOptions, hooks::use_navigate};
+
+use crate::api::{cancel_pending_requests, clear_query_cache};
+use crate::routes::AppRoute;
+use crate::session::context::use_session;
+use crate::utils::{drafts::clear_drafts, unsaved_changes::confirm_leave};
+
+type Cleanup = Box<dyn Fn()>;
+
+/// Cleanups features registered through `on_logout`.
+#[derive(Copy, Clone)]
+struct LogoutHooks {
+    next_id: StoredValue<usize>,
+    hooks: StoredValue<Vec<(usize, Cleanup)>, LocalStorage>,
+}
+
+impl LogoutHooks {
+    fn run(&self) {
+        self.hooks.with_value(|hooks| hooks.iter().for_each(|(_, cleanup)| cleanup()));
+    }
+}
+
+/// Provides the cleanup registry and runs every cleanup when the session ends,
+/// whether the logout happened in this tab or in another one.
+pub fn provide_logout() {
+    let hooks = LogoutHooks {
+        next_id: StoredValue::new(0),
+        hooks: StoredValue::new_local(Vec::new()),
+    };
+    provide_context(hooks);
+
+    let session = use_session();
+    Effect::new(move |was_authenticated: Option<bool>| {
+        let is_authenticated = session.is_authenticated();
+        if was_authenticated == Some(true) && !is_authenticated {
+            cancel_pending_requests();
+            clear_query_cache();
+            clear_drafts();
+            // The sidebar needs no reset: it unmounts with `PrivateLayout` and
+            // starts from `default_open` again on the next login
+            hooks.run();
+        }
+        is_authenticated
+    });
+}
+
+/// Registers a cleanup to run on logout for as long as the calling owner is alive.
+pub fn on_logout(cleanup: impl Fn() + 'static) {
+    let Some(hooks) = use_context::<LogoutHooks>() else {
+        return;
+    };
+
+    let id = hooks.next_id.get_value();
+    hooks.next_id.set_value(id + 1);
+    hooks.hooks.update_value(|list| list.push((id, Box::new(cleanup))));
+
+    on_cleanup(move || {
+        hooks.hooks.update_value(|list| list.retain(|(hook_id, _)| *hook_id != id));
+    });
+}
+
+/// Returns the logout action: ends the session (which notifies other tabs and
+/// triggers the cleanups) and replaces the current history entry with `/login`.
+/// Nothing happens if the user keeps a form with unsaved changes.
+pub fn use_logout() -> impl Fn() + Clone + 'static {
+    let session = use_session();
+    let navigate = use_navigate();
+
+    move || {
+        if !confirm_leave() {
+            return;
+        }
+        cancel_pending_requests();
+        session.sign_out();
+        navigate(
+            &AppRoute::Login.href(),
+            NavigateOptions {
+                replace: true,
+                ..Default::default()
+            },
+        );
+    }
+}
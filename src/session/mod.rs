@@ -0,0 +1,6 @@
+// session/mod.rs
+
+pub mod context;
+pub mod logout;
+pub mod store;
+pub mod sync;
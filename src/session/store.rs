@@ -0,0 +1,156 @@
+use std::sync::{Arc, Mutex};
+
+use gloo_storage::{LocalStorage, SessionStorage, Storage};
+use leptos::{prelude::*, server::codee::string::FromToStringCodec};
+use leptos_use::{SameSite, UseCookieOptions, use_cookie_with_options};
+
+/// Key (cookie name / storage key) the auth token is saved under.
+pub const TOKEN_KEY: &str = "auth_token";
+/// Key the refresh token, when the backend issues one, is saved under.
+pub const REFRESH_TOKEN_KEY: &str = "refresh_token";
+
+/// A place the auth token can be persisted to between page loads.
+pub trait SessionStore: Send + Sync {
+    fn load(&self) -> Option<String>;
+    fn save(&self, token: &str);
+    fn clear(&self);
+}
+
+/// Attributes written alongside the auth cookie.
+#[derive(Clone, Debug)]
+pub struct CookieAttributes {
+    pub path: String,
+    /// Lifetime in milliseconds, `None` makes it a browser-session cookie
+    pub max_age: Option<i64>,
+    pub same_site: SameSite,
+    pub secure: bool,
+}
+
+impl Default for CookieAttributes {
+    fn default() -> Self {
+        Self {
+            path: String::from("/"),
+            max_age: None,
+            same_site: SameSite::Lax,
+            secure: true,
+        }
+    }
+}
+
+/// Selects which `SessionStore` implementation to build.
+#[derive(Clone, Debug)]
+pub enum StoreKind {
+    Cookie(CookieAttributes),
+    LocalStorage,
+    SessionStorage,
+    Memory,
+}
+
+impl StoreKind {
+    /// Must be called inside a reactive owner, the cookie store relies on `use_cookie`.
+    pub fn build(self, key: &'static str) -> Arc<dyn SessionStore> {
+        match self {
+            StoreKind::Cookie(attributes) => Arc::new(CookieStore::new(key, attributes)),
+            StoreKind::LocalStorage => Arc::new(LocalStorageStore { key }),
+            StoreKind::SessionStorage => Arc::new(SessionStorageStore { key }),
+            StoreKind::Memory => Arc::new(MemoryStore::default()),
+        }
+    }
+}
+
+// Cookie
+pub struct CookieStore {
+    cookie: Signal<Option<String>>,
+    set_cookie: WriteSignal<Option<String>>,
+}
+
+impl CookieStore {
+    pub fn new(name: &str, attributes: CookieAttributes) -> Self {
+        let mut options = UseCookieOptions::default()
+            .path(attributes.path)
+            .same_site(attributes.same_site)
+            .secure(attributes.secure);
+        if let Some(max_age) = attributes.max_age {
+            options = options.max_age(max_age);
+        }
+
+        let (cookie, set_cookie) = use_cookie_with_options::<String, FromToStringCodec>(name, options);
+        Self { cookie, set_cookie }
+    }
+}
+
+impl SessionStore for CookieStore {
+    fn load(&self) -> Option<String> {
+        self.cookie.get_untracked()
+    }
+
+    fn save(&self, token: &str) {
+        self.set_cookie.set(Some(token.to_string()));
+    }
+
+    fn clear(&self) {
+        self.set_cookie.set(None);
+    }
+}
+
+// localStorage
+pub struct LocalStorageStore {
+    key: &'static str,
+}
+
+impl SessionStore for LocalStorageStore {
+    fn load(&self) -> Option<String> {
+        LocalStorage::get(self.key).ok()
+    }
+
+    fn save(&self, token: &str) {
+        let _ = LocalStorage::set(self.key, token);
+    }
+
+    fn clear(&self) {
+        LocalStorage::delete(self.key);
+    }
+}
+
+// sessionStorage
+pub struct SessionStorageStore {
+    key: &'static str,
+}
+
+impl SessionStore for SessionStorageStore {
+    fn load(&self) -> Option<String> {
+        SessionStorage::get(self.key).ok()
+    }
+
+    fn save(&self, token: &str) {
+        let _ = SessionStorage::set(self.key, token);
+    }
+
+    fn clear(&self) {
+        SessionStorage::delete(self.key);
+    }
+}
+
+// Memory, lost on reload
+#[derive(Default)]
+pub struct MemoryStore {
+    token: Mutex<Option<String>>,
+}
+
+impl SessionStore for MemoryStore {
+    fn load(&self) -> Option<String> {
+        self.token.lock().ok().and_then(|token| token.clone())
+    }
+
+    fn save(&self, token: &str) {
+        if let Ok(mut current) = self.token.lock() {
+            *current = Some(token.to_string());
+        }
+    }
+
+    fn clear(&self) {
+        if let Ok(mut current) = self.token.lock() {
+            *current = None;
+        }
+    }
+}
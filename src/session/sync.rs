@@ -0,0 +1,74 @@
+use std::{fmt, str::FromStr};
+
+use gloo_storage::{LocalStorage, Storage};
+use leptos::{ev, prelude::*, server::codee::string::FromToStringCodec};
+use leptos_use::{use_broadcast_channel, use_event_listener};
+
+const CHANNEL_NAME: &str = "auth_token:sync";
+
+/// Session changes that other open tabs need to pick up.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SessionEvent {
+    LoggedIn(String),
+    LoggedOut,
+    TokenRefreshed(String),
+}
+
+impl fmt::Display for SessionEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SessionEvent::LoggedIn(token) => write!(f, "login:{token}"),
+            SessionEvent::LoggedOut => write!(f, "logout"),
+            SessionEvent::TokenRefreshed(token) => write!(f, "refresh:{token}"),
+        }
+    }
+}
+
+impl FromStr for SessionEvent {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(':') {
+            Some(("login", token)) => Ok(SessionEvent::LoggedIn(token.to_string())),
+            Some(("refresh", token)) => Ok(SessionEvent::TokenRefreshed(token.to_string())),
+            None if s == "logout" => Ok(SessionEvent::LoggedOut),
+            _ => Err(format!("unknown session event: {s}")),
+        }
+    }
+}
+
+/// Listens for session events from other tabs and returns a function that
+/// broadcasts this tab's events to them.
+///
+/// Uses `BroadcastChannel` when available, otherwise falls back to `storage`
+/// events on a localStorage key that is written and removed straight away.
+pub fn use_session_sync(on_event: impl Fn(SessionEvent) + Clone + 'static) -> impl Fn(&SessionEvent) + 'static {
+    let channel = use_broadcast_channel::<SessionEvent, FromToStringCodec>(CHANNEL_NAME);
+
+    let on_message = on_event.clone();
+    Effect::new(move |_| {
+        if let Some(event) = channel.message.get() {
+            on_message(event);
+        }
+    });
+
+    let is_supported = channel.is_supported;
+    let _ = use_event_listener(window(), ev::storage, move |event| {
+        if is_supported.get_untracked() || event.key().as_deref() != Some(CHANNEL_NAME) {
+            return;
+        }
+        if let Some(event) = event.new_value().and_then(|value| value.parse().ok()) {
+            on_event(event);
+        }
+    });
+
+    let post = channel.post;
+    move |event: &SessionEvent| {
+        if is_supported.get_untracked() {
+            post(event);
+        } else {
+            let _ = LocalStorage::raw().set_item(CHANNEL_NAME, &event.to_string());
+            LocalStorage::delete(CHANNEL_NAME);
+        }
+    }
+}
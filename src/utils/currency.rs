@@ -0,0 +1,48 @@
+/// Store currency, FakeStore prices are plain USD amounts.
+pub struct Currency {
+    pub code: &'static str,
+    pub symbol: &'static str,
+    pub decimals: usize,
+}
+
+pub const CURRENCY: Currency = Currency {
+    code: "USD",
+    symbol: "$",
+    decimals: 2,
+};
+
+/// `1234.5` -> `$1,234.50`
+pub fn format_price(amount: f64) -> String {
+    let formatted = format!("{:.*}", CURRENCY.decimals, amount.abs());
+    let (whole, fraction) = formatted.split_once('.').unwrap_or((&formatted, ""));
+
+    let mut grouped = String::new();
+    for (index, digit) in whole.chars().enumerate() {
+        if index > 0 && (whole.len() - index) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+
+    let sign = if amount < 0.0 { "-" } else { "" };
+    if fraction.is_empty() {
+        format!("{sign}{}{grouped}", CURRENCY.symbol)
+    } else {
+        format!("{sign}{}{grouped}.{fraction}", CURRENCY.symbol)
+    }
+}
+
+/// Accepts what people type into a price field: `$1,234.5`, ` 12 `, `12.999`.
+/// Rounds to the currency's decimals, `None` for anything that is not a number.
+pub fn parse_price(input: &str) -> Option<f64> {
+    let cleaned: String = input
+        .trim()
+        .trim_start_matches(CURRENCY.symbol)
+        .trim_end_matches(CURRENCY.code)
+        .chars()
+        .filter(|c| *c != ',' && !c.is_whitespace())
+        .collect();
+    let amount = cleaned.parse::<f64>().ok().filter(|amount| amount.is_finite())?;
+    let factor = 10f64.powi(CURRENCY.decimals as i32);
+    Some((amount * factor).round() / factor)
+}
@@ -0,0 +1,28 @@
+use gloo_storage::{LocalStorage, Storage};
+use serde::{Serialize, de::DeserializeOwned};
+
+// Every draft lives in localStorage under this prefix so logout can wipe them in one go
+const DRAFT_PREFIX: &str = "draft:";
+
+pub fn save_draft<T: Serialize>(name: &str, value: &T) {
+    let _ = LocalStorage::set(format!("{DRAFT_PREFIX}{name}"), value);
+}
+
+pub fn load_draft<T: DeserializeOwned>(name: &str) -> Option<T> {
+    LocalStorage::get(format!("{DRAFT_PREFIX}{name}")).ok()
+}
+
+pub fn discard_draft(name: &str) {
+    LocalStorage::delete(format!("{DRAFT_PREFIX}{name}"));
+}
+
+pub fn clear_drafts() {
+    let storage = LocalStorage::raw();
+    let keys: Vec<String> = (0..LocalStorage::length())
+        .filter_map(|index| storage.key(index).ok().flatten())
+        .filter(|key| key.starts_with(DRAFT_PREFIX))
+        .collect();
+    for key in keys {
+        let _ = storage.remove_item(&key);
+    }
+}
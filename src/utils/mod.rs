@@ -1 +1,6 @@
-pub mod cn;
\ No newline at end of file
+pub mod cn;
+pub mod currency;
+pub mod drafts;
+pub mod scroll_restoration;
+pub mod unsaved_changes;
+pub mod validation;
\ No newline at end of file
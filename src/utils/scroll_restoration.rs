@@ -0,0 +1,64 @@
+use leptos::{ev, prelude::*, web_sys};
+use leptos_router::hooks::use_location;
+use leptos_use::use_event_listener;
+use std::collections::HashMap;
+use std::time::Duration;
+
+// Content of data pages may still be loading when a back/forward navigation
+// lands, so restoring retries until the page is tall enough
+const RESTORE_ATTEMPTS: u32 = 20;
+const RESTORE_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Scrolls to the top on new navigations and back to the saved position
+/// on back/forward.
+///
+/// Positions are remembered per pathname while scrolling. Changes to the
+/// query string alone (filters, pagination) keep the current position.
+pub fn use_scroll_restoration() {
+    if let Ok(history) = window().history() {
+        let _ = history.set_scroll_restoration(web_sys::ScrollRestoration::Manual);
+    }
+
+    let pathname = use_location().pathname;
+    let positions = StoredValue::new(HashMap::<String, f64>::new());
+    let popped = StoredValue::new(false);
+    // Bumped on every navigation so a pending restore stops retrying
+    let generation = StoredValue::new(0_u32);
+
+    let _ = use_event_listener(window(), ev::scroll, move |_| {
+        let y = window().scroll_y().unwrap_or_default();
+        positions.update_value(|positions| {
+            positions.insert(pathname.get_untracked(), y);
+        });
+    });
+
+    let _ = use_event_listener(window(), ev::popstate, move |_| popped.set_value(true));
+
+    Effect::new(move |previous: Option<String>| {
+        let path = pathname.get();
+        if previous.is_some_and(|previous| previous != path) {
+            generation.update_value(|generation| *generation += 1);
+            let back_or_forward = popped.get_value();
+            popped.set_value(false);
+            let target = if back_or_forward {
+                positions.with_value(|positions| positions.get(&path).copied()).unwrap_or_default()
+            } else {
+                0.0
+            };
+            let current = generation.get_value();
+            request_animation_frame(move || restore(target, RESTORE_ATTEMPTS, move || generation.get_value() == current));
+        }
+        path
+    });
+}
+
+fn restore(y: f64, attempts: u32, still_current: impl Fn() -> bool + Clone + 'static) {
+    if !still_current() {
+        return;
+    }
+    window().scroll_to_with_x_and_y(0.0, y);
+    let reached = window().scroll_y().is_ok_and(|scrolled| (scrolled - y).abs() < 1.0);
+    if !reached && attempts > 1 {
+        set_timeout(move || restore(y, attempts - 1, still_current), RESTORE_INTERVAL);
+    }
+}
@@ -0,0 +1,85 @@
+use leptos::{ev, prelude::*, wasm_bindgen::{JsCast, JsValue}, web_sys};
+use leptos_router::hooks::use_location;
+use leptos_use::{UseEventListenerOptions, use_event_listener, use_event_listener_with_options};
+use std::cell::RefCell;
+
+const PROMPT: &str = "You have unsaved changes. Leave this page and discard them?";
+
+thread_local! {
+    // `dirty` signals of the mounted forms, keyed so each can unregister itself
+    static GUARDS: RefCell<Vec<(usize, Signal<bool>)>> = const { RefCell::new(Vec::new()) };
+    static NEXT_GUARD_ID: RefCell<usize> = const { RefCell::new(0) };
+}
+
+/// True when no mounted form has unsaved changes, or the user agreed to
+/// discard them. Call it before a programmatic navigation the form itself
+/// did not start, such as logout. A form's own redirect after saving
+/// runs once its changes are no longer dirty.
+pub fn confirm_leave() -> bool {
+    let dirty = GUARDS.with_borrow(|guards| guards.iter().any(|(_, dirty)| dirty.get_untracked()));
+    !dirty || window().confirm_with_message(PROMPT).unwrap_or(true)
+}
+
+/// Asks for confirmation before leaving the page while `dirty` is true:
+/// on reload/close through `beforeunload`, on in-app links by intercepting
+/// their clicks before the router handles them, on browser back/forward,
+/// and on programmatic navigations guarded by `confirm_leave`.
+pub fn use_unsaved_changes_prompt(dirty: Signal<bool>) {
+    let id = NEXT_GUARD_ID.with_borrow_mut(|next| {
+        *next += 1;
+        *next
+    });
+    GUARDS.with_borrow_mut(|guards| guards.push((id, dirty)));
+    on_cleanup(move || GUARDS.with_borrow_mut(|guards| guards.retain(|(guard_id, _)| *guard_id != id)));
+
+    let _ = use_event_listener(window(), ev::beforeunload, move |event| {
+        if dirty.get_untracked() {
+            event.prevent_default();
+        }
+    });
+
+    let _ = use_event_listener_with_options(
+        document(),
+        ev::click,
+        move |event| {
+            if !dirty.get_untracked() {
+                return;
+            }
+            let link = event
+                .target()
+                .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+                .and_then(|element| element.closest("a[href]").ok().flatten());
+            if link.is_some() && !window().confirm_with_message(PROMPT).unwrap_or(true) {
+                event.prevent_default();
+                event.stop_propagation();
+            }
+        },
+        UseEventListenerOptions::default().capture(true),
+    );
+
+    // The browser has already moved to the other entry when popstate fires.
+    // Capture listeners on the window run before the router's own one, so a
+    // cancel can hide the event from the router and push the form's URL back.
+    let location = use_location();
+    let current_url = StoredValue::new(String::new());
+    Effect::new(move |_| {
+        location.pathname.track();
+        location.search.track();
+        location.hash.track();
+        current_url.set_value(window().location().href().unwrap_or_default());
+    });
+    let _ = use_event_listener_with_options(
+        window(),
+        ev::popstate,
+        move |event| {
+            if !dirty.get_untracked() || window().confirm_with_message(PROMPT).unwrap_or(true) {
+                return;
+            }
+            event.stop_immediate_propagation();
+            if let Ok(history) = window().history() {
+                let _ = history.push_state_with_url(&JsValue::NULL, "", Some(&current_url.get_value()));
+            }
+        },
+        UseEventListenerOptions::default().capture(true),
+    );
+}